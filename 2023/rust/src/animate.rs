@@ -0,0 +1,174 @@
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, IsTerminal, Read, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::render::{Color, Frame};
+
+// roughly how many frames a day should produce, so an animation lasts seconds and not minutes
+const MAX_FRAMES: usize = 400;
+
+const START_DELAY: Duration = Duration::from_millis(60);
+const MIN_DELAY: Duration = Duration::from_millis(5);
+const MAX_DELAY: Duration = Duration::from_millis(2000);
+
+const HELP: &str = "[space] pause  [n/b] step  [+/-] speed  [q] quit";
+
+// The steps of a simulation with `steps` of them to take a frame at, evenly spread and always including the first and last state
+pub fn checkpoints(steps: usize) -> Vec<usize> {
+    let every = steps.div_ceil(MAX_FRAMES).max(1);
+
+    (0..steps).step_by(every).chain([steps]).collect()
+}
+
+/**
+ * The terminal the keys are read from, switched to unbuffered input without echo while it is open.
+ * Without a terminal (e.g. piped output) the animation just plays through.
+ */
+struct Terminal {
+    tty: Option<File>,
+    // `stty -g` settings to restore
+    saved: String
+}
+
+fn stty(tty: &File, args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::from(tty.try_clone().ok()?))
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+impl Terminal {
+    fn open() -> Self {
+        let tty = match File::open("/dev/tty") {
+            Ok(tty) if io::stdout().is_terminal() => tty,
+            _ => return Self { tty: None, saved: String::new() }
+        };
+
+        // no line buffering or echo, and reads return at once when no key was pressed
+        match stty(&tty, &["-g"]) {
+            Some(saved) if stty(&tty, &["-icanon", "-echo", "min", "0", "time", "0"]).is_some() => {
+                Self { tty: Some(tty), saved }
+            },
+            _ => Self { tty: None, saved: String::new() }
+        }
+    }
+
+    fn interactive(&self) -> bool {
+        self.tty.is_some()
+    }
+
+    fn key(&mut self) -> Option<u8> {
+        let mut buf = [0; 1];
+        match self.tty.as_mut()?.read(&mut buf) {
+            Ok(1) => Some(buf[0]),
+            _ => None
+        }
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        if let Some(tty) = &self.tty {
+            stty(tty, &[&self.saved]);
+        }
+        // show the cursor again
+        print!("\x1b[?25h");
+        let _ = io::stdout().flush();
+    }
+}
+
+// Two rows per line of text: the upper half block takes the top cell as foreground and the bottom one as background
+fn draw(frame: &Frame, status: &str) -> String {
+    let cells = &frame.cells;
+    let mut out = String::from("\x1b[H");
+
+    for y in (0..cells.height()).step_by(2) {
+        let mut last = None;
+        for x in 0..cells.width() {
+            let top = cells[(x, y)];
+            let bottom = if y + 1 < cells.height() { cells[(x, y + 1)] } else { Color::BLACK };
+            if last != Some((top, bottom)) {
+                write!(out, "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m", top.0, top.1, top.2, bottom.0, bottom.1, bottom.2).unwrap();
+                last = Some((top, bottom));
+            }
+            out.push('▀');
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    writeln!(out, "{}\x1b[K", status).unwrap();
+
+    out
+}
+
+/**
+ * Plays the frames in the terminal, with keys to pause, step and change the speed when there is one.
+ * It stops at the last frame, or when quitting.
+ */
+pub fn play(frames: &[Frame]) -> io::Result<()> {
+    if frames.is_empty() {
+        return Ok(());
+    }
+
+    let mut terminal = Terminal::open();
+    let mut stdout = io::stdout();
+
+    // clear the screen and hide the cursor
+    write!(stdout, "\x1b[2J\x1b[?25l")?;
+
+    let mut index = 0;
+    let mut paused = false;
+    let mut delay = START_DELAY;
+    // what is on screen: (frame, paused, delay)
+    let mut shown = None;
+    let mut last_step = Instant::now();
+
+    loop {
+        if shown != Some((index, paused, delay)) {
+            let mut status = format!("{} {}/{}  {} ms/frame", frames[index].name, index + 1, frames.len(), delay.as_millis());
+            if terminal.interactive() {
+                status.push_str(if paused { "  PAUSED  " } else { "  " });
+                status.push_str(HELP);
+            }
+            stdout.write_all(draw(&frames[index], &status).as_bytes())?;
+            stdout.flush()?;
+            shown = Some((index, paused, delay));
+        }
+
+        let last = index == frames.len() - 1;
+        if last && !terminal.interactive() {
+            break;
+        }
+
+        match terminal.key() {
+            Some(b'q') => break,
+            Some(b' ') => paused = !paused,
+            Some(b'n') => {
+                paused = true;
+                index = (index + 1).min(frames.len() - 1);
+            },
+            Some(b'b') => {
+                paused = true;
+                index = index.saturating_sub(1);
+            },
+            Some(b'+') => delay = (delay / 2).max(MIN_DELAY),
+            Some(b'-') => delay = (delay * 2).min(MAX_DELAY),
+            _ => {}
+        }
+
+        if !paused && !last && last_step.elapsed() >= delay {
+            index += 1;
+            last_step = Instant::now();
+        }
+
+        thread::sleep(MIN_DELAY);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use std::fs;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::days::Day;
+use crate::input::ParseResult;
+use crate::solution::Puzzle;
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+// how much slower than the baseline, in percent, a phase can get before it is a regression
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+// below this a phase is all noise, the microsecond days would otherwise flag a regression on every run
+const NOISE_NS: u64 = 100_000;
+
+pub const PHASES: [&str; 3] = ["parse", "part 1", "part 2"];
+
+// The spread of the timings of one phase over all the iterations
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub median_ns: u64,
+    pub p95_ns: u64
+}
+
+impl Timing {
+    fn new(mut samples: Vec<Duration>) -> Timing {
+        samples.sort();
+
+        Timing {
+            median_ns: percentile(&samples, 50).as_nanos() as u64,
+            p95_ns: percentile(&samples, 95).as_nanos() as u64
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DayTimings {
+    pub day: u8,
+    pub name: String,
+    pub iterations: usize,
+    pub parse: Timing,
+    pub part1: Timing,
+    pub part2: Timing
+}
+
+impl DayTimings {
+    // in the order of `PHASES`
+    pub fn phases(&self) -> [Timing; 3] {
+        [self.parse, self.part1, self.part2]
+    }
+}
+
+/**
+ * The JSON written by `aoc bench --output`, and read back with `--baseline`.
+ */
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Report {
+    pub days: Vec<DayTimings>
+}
+
+impl Report {
+    pub fn read(path: &str) -> Result<Report, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("Could not read the baseline {}: {}", path, e))?;
+
+        serde_json::from_str(&text).map_err(|e| format!("Invalid baseline {}: {}", path, e))
+    }
+
+    pub fn write(&self, path: &str) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).unwrap();
+
+        fs::write(path, json + "\n").map_err(|e| format!("Could not write {}: {}", path, e))
+    }
+}
+
+// A phase whose median got slower than in the baseline by more than the threshold
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub phase: &'static str,
+    pub baseline_ns: u64,
+    pub current_ns: u64
+}
+
+impl Regression {
+    pub fn percent(&self) -> f64 {
+        (self.current_ns as f64 / self.baseline_ns as f64 - 1.0) * 100.0
+    }
+}
+
+// The nearest-rank percentile of sorted samples
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (sorted.len() * p).div_ceil(100).max(1);
+
+    sorted[rank - 1]
+}
+
+/**
+ * Times parsing and each part of the day on `text`, `iterations` times after a first run to warm up.
+ * Fails when the input does not parse.
+ */
+pub fn bench(day: &Day, text: &str, puzzle: Puzzle, iterations: usize) -> ParseResult<DayTimings> {
+    (day.time)(text, puzzle)?;
+
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..iterations {
+        let times = (day.time)(text, puzzle)?;
+        for (phase, time) in samples.iter_mut().zip(times) {
+            phase.push(time);
+        }
+    }
+
+    let [parse, part1, part2] = samples.map(Timing::new);
+
+    Ok(DayTimings { day: day.day, name: day.name.to_string(), iterations, parse, part1, part2 })
+}
+
+/**
+ * The phases of the days in both reports whose median is more than `threshold` percent slower now.
+ * Days missing from either report are not compared.
+ */
+pub fn regressions(baseline: &Report, current: &Report, threshold: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+
+    for now in &current.days {
+        let Some(before) = baseline.days.iter().find(|d| d.day == now.day) else {
+            continue;
+        };
+
+        for ((phase, old), new) in PHASES.iter().zip(before.phases()).zip(now.phases()) {
+            let slower = new.median_ns as f64 > old.median_ns as f64 * (1.0 + threshold / 100.0);
+
+            if slower && new.median_ns > NOISE_NS {
+                regressions.push(Regression { day: now.day, phase, baseline_ns: old.median_ns, current_ns: new.median_ns });
+            }
+        }
+    }
+
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings(day: u8, medians: [u64; 3]) -> DayTimings {
+        let [parse, part1, part2] = medians.map(|median_ns| Timing { median_ns, p95_ns: median_ns });
+
+        DayTimings { day, name: format!("day {}", day), iterations: 1, parse, part1, part2 }
+    }
+
+    #[test]
+    fn percentiles_use_the_nearest_rank() {
+        let samples: Vec<Duration> = (1..=20).map(Duration::from_millis).collect();
+
+        assert_eq!(percentile(&samples, 50), Duration::from_millis(10));
+        assert_eq!(percentile(&samples, 95), Duration::from_millis(19));
+        assert_eq!(percentile(&samples[..1], 95), Duration::from_millis(1));
+
+        let timing = Timing::new(vec![Duration::from_nanos(30), Duration::from_nanos(10), Duration::from_nanos(20)]);
+        assert_eq!(timing, Timing { median_ns: 20, p95_ns: 30 });
+    }
+
+    #[test]
+    fn only_slower_phases_above_the_threshold_regress() {
+        let baseline = Report { days: vec![timings(5, [1_000_000, 2_000_000, 3_000_000]), timings(12, [10, 10, 10])] };
+        let current = Report { days: vec![
+            timings(5, [1_050_000, 2_500_000, 1_000_000]),
+            // far slower, but still too fast to tell from noise
+            timings(12, [90_000, 90_000, 90_000]),
+            // not in the baseline
+            timings(17, [9_000_000, 9_000_000, 9_000_000])
+        ] };
+
+        let found = regressions(&baseline, &current, 10.0);
+
+        assert_eq!(found, vec![Regression { day: 5, phase: "part 1", baseline_ns: 2_000_000, current_ns: 2_500_000 }]);
+        assert_eq!(found[0].percent(), 25.0);
+        assert!(regressions(&baseline, &current, 30.0).is_empty());
+    }
+}
@@ -1,8 +1,6 @@
-mod utils;
+use crate::input::ParseResult;
+use crate::solution::Solution;
 
-static CONVERT_NUMBERS: bool = true;
-
-static INPUT_FILE: &str = "./input/01_input.txt";
 static NUMBERS: [(&str, &str); 9] = [("1", "one"), ("2", "two"), ("3", "three"), ("4", "four"), ("5", "five"), ("6", "six"), ("7", "seven"), ("8", "eight"), ("9", "nine")];
 
 fn convert_spelled_numbers_to_digits(text: &str) -> String {
@@ -16,7 +14,7 @@ fn get_value(text: &str) -> u8 {
     let mut last_dig: Option<char> = None; 
     
     for c in text.chars() { 
-        if c.is_digit(10) {
+        if c.is_ascii_digit() {
             match first_dig {
                 Some(_) => last_dig = Some(c),
                 None => {
@@ -34,27 +32,25 @@ fn get_value(text: &str) -> u8 {
     }
  }
 
-fn main() {
-    if let Ok(lines) = utils::read_lines(INPUT_FILE) {
-        let mut sum: u32 = 0;
-        for line in lines {
-            if let Ok(text) = line {
-                let value: u8 = if CONVERT_NUMBERS == true {
-                    let converted_text: String = convert_spelled_numbers_to_digits(&text);
-                    get_value(&converted_text)
-                } else {
-                    get_value(&text)
-                };
-
-                // println!("{} = {}", &text, &value);
-
-                sum += value as u32;
-            }
-        }
+pub struct Trebuchet;
 
-        println!("Sum of all calibration values: {}", sum);
-    } else {
-        eprintln!("Could not extract codes from {}", INPUT_FILE);
+impl Solution for Trebuchet {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const PART1: &'static str = "Sum of all calibration values";
+    const PART2: &'static str = "Sum of all calibration values with spelled numbers";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        input.iter().map(|text| get_value(text) as u32).sum()
     }
 
+    fn part2(input: &Self::Input) -> u32 {
+        input.iter().map(|text| get_value(&convert_spelled_numbers_to_digits(text)) as u32).sum()
+    }
 }
@@ -1,12 +1,11 @@
 use std::fmt::Display;
 use std::cmp::Ordering;
 
-mod utils;
-
-static INPUT_FILE: &str = "./input/02_input.txt";
+use crate::input::{self, ParseError, ParseResult};
+use crate::solution::Solution;
 
 #[derive(Eq)]
-struct Game {
+pub struct Game {
     id: u32,
     red: u32,
     green: u32,
@@ -53,65 +52,55 @@ impl Display for Game {
     }
 }
 
-fn parse_game(text: &str) -> Game {
+fn parse_game(text: &str) -> ParseResult<Game> {
     let mut red_max = 0;
     let mut green_max = 0;
     let mut blue_max = 0;
     
-    let mut parts = text.split(':');
-    let game = parts.next().expect("No Game ID found!");
-    let body = parts.next().expect("No Game Cubes found!");
-    let sets = body.split(';');
-    for set in sets {
-        let cubes = set.split(',');
-        for cube in cubes {
-            let mut cube_parts = cube.trim().split(' ');
-            let count = cube_parts.next().expect("No Cube count found!");
-            let color = cube_parts.next().expect("No Cube color found!");
-
-            let count = count.parse::<u32>().unwrap();
+    let (game, body) = input::split_once(text, text, ":", "Game Cubes")?;
+    let id = input::value(text, input::after(text, game, "Game")?, "Game ID")?;
+
+    for set in body.split(';') {
+        for cube in set.split(',') {
+            let [count, color] = input::fields(text, cube, "words for a cube count and color")?;
+            let count: u32 = input::value(text, count, "Cube count")?;
 
             match color {
                 "red" => if count > red_max { red_max = count },
                 "green" => if count > green_max { green_max = count },
                 "blue" => if count > blue_max { blue_max = count },
-                _ => unreachable!("Invalid Color '{}'", color)
+                _ => return Err(ParseError::at(text, color, format!("invalid color '{}'", color)))
             }
         }
     }
 
-    let id = game[5..].parse().unwrap();
-
-    Game { id, red: red_max, green: green_max, blue: blue_max }
+    Ok(Game { id, red: red_max, green: green_max, blue: blue_max })
 }
 
-fn main() {
-    if let Ok(lines) = utils::read_lines(INPUT_FILE) {
-        let mut sum_valid: u32 = 0;
-        let mut sum_power: u32 = 0;
+pub struct CubeConundrum;
 
-        // 12 red cubes, 13 green cubes, and 14 blue cubes
-        let test_game = Game { id: 0, red: 12, green: 13, blue: 14 };
+impl Solution for CubeConundrum {
+    type Input = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-        for line in lines {
-            if let Ok(text) = line {
-                let game = parse_game(&text);
+    const PART1: &'static str = "Sum of valid Game IDs";
+    const PART2: &'static str = "Sum of powers";
 
-                let power = game.red * game.green * game.blue;
-                sum_power += power;
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input::parse_lines(input, parse_game)
+    }
 
-                if game <= test_game {
-                    sum_valid += game.id;
-                }
+    fn part1(input: &Self::Input) -> u32 {
+        // 12 red cubes, 13 green cubes, and 14 blue cubes
+        let test_game = Game { id: 0, red: 12, green: 13, blue: 14 };
 
-                //println!("{} is {}", game, if game <= test_game { "VALID" } else { "INVALID" });
-            }
-        }
+        //input.iter().for_each(|game| println!("{} is {}", game, if *game <= test_game { "VALID" } else { "INVALID" }));
 
-        println!("Sum of valid Game IDs: {}", sum_valid);
-        println!("Sum of powers: {}", sum_power);
-    } else {
-        eprintln!("Could not extract games from {}", INPUT_FILE);
+        input.iter().filter(|game| **game <= test_game).map(|game| game.id).sum()
     }
 
+    fn part2(input: &Self::Input) -> u32 {
+        input.iter().map(|game| game.red * game.green * game.blue).sum()
+    }
 }
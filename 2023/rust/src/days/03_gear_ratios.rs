@@ -0,0 +1,115 @@
+use std::cmp::Eq;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::input::ParseResult;
+use crate::solution::Solution;
+use crate::utils::grid::Grid;
+
+#[derive(Eq)]
+#[derive(Clone)]
+#[derive(Copy)]
+struct Symbol {
+    ch: char,
+    row: usize,
+    col: usize
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        self.ch == other.ch &&
+        self.row == other.row &&
+        self.col == other.col
+    }
+}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ch.hash(state);
+        self.row.hash(state);
+        self.col.hash(state);
+    }
+}
+
+fn is_symbol(ch: char) -> bool {
+    !ch.is_ascii_digit() && ch != '.'
+}
+
+// the symbols around the digits of a number
+fn get_adjacent_symbols(digits: &[(usize, usize)], schematic: &Grid<char>) -> Vec<Symbol> {
+    let mut symbols: Vec<Symbol> = Vec::new();
+
+    for pos in digits {
+        for (col, row) in schematic.neighbours8(*pos) {
+            let ch = schematic[(col, row)];
+            let symbol = Symbol { ch, row, col };
+            if is_symbol(ch) && !symbols.contains(&symbol) {
+                symbols.push(symbol);
+            }
+        }
+    }
+
+    symbols
+}
+
+// numbers with at least one adjacent symbol, along with those symbols
+fn find_part_numbers(schematic: &Grid<char>) -> Vec<(u32, Vec<Symbol>)> {
+    let mut part_numbers = Vec::new();
+
+    for (row, line) in schematic.rows().enumerate() {
+        let mut col = 0;
+        while col < line.len() {
+            if !line[col].is_ascii_digit() {
+                col += 1;
+                continue;
+            }
+
+            let len = line[col..].iter().take_while(|c| c.is_ascii_digit()).count();
+            let num = line[col..col + len].iter().fold(0, |acc, c| acc * 10 + c.to_digit(10).unwrap());
+            let digits: Vec<(usize, usize)> = (col..col + len).map(|c| (c, row)).collect();
+
+            let symbols = get_adjacent_symbols(&digits, schematic);
+            if !symbols.is_empty() {
+                part_numbers.push((num, symbols));
+            }
+
+            col += len;
+        }
+    }
+
+    part_numbers
+}
+
+pub struct GearRatios;
+
+impl Solution for GearRatios {
+    type Input = Grid<char>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const PART1: &'static str = "Sum of engine schematic part numbers";
+    const PART2: &'static str = "Sum of gear ratios";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        Grid::parse(input, |ch| ch.is_ascii_graphic())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        find_part_numbers(input).iter().map(|(num, _)| num).sum()
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        let mut symbol_nums_map: HashMap<Symbol, Vec<u32>> = HashMap::new();
+
+        for (num, symbols) in find_part_numbers(input) {
+            for sym in symbols.iter().filter(|sym| sym.ch == '*') {
+                symbol_nums_map.entry(*sym).or_default().push(num);
+            }
+        }
+
+        // calculate gear rations
+        symbol_nums_map.iter()
+            .filter(|(_, nums)| nums.len() == 2)
+            .fold(0, |acc, (_, nums)| acc + nums.iter().copied().reduce(|acc, num| num * acc).unwrap())
+    }
+}
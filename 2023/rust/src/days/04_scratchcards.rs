@@ -0,0 +1,82 @@
+use crate::input::{self, ParseResult};
+use crate::solution::Solution;
+
+pub struct Card {
+    _id: u32,
+    winning: Vec<u32>,
+    values: Vec<u32>
+}
+
+impl Card {
+    fn calc_points(&self) -> usize {
+        let count = self.values.iter()
+            .filter(|value| self.winning.contains(value))
+            .count();
+        if count < 2 {
+            count
+        } else {
+            2_usize.pow((count - 1) as u32)
+        }
+    }
+
+    fn count_matches(&self) -> usize {
+        self.values.iter()
+            .filter(|value| self.winning.contains(value))
+            .count()
+    }
+}
+
+fn parse_card(text: &str) -> ParseResult<Card> {
+    let (card, numbers) = input::split_once(text, text, ":", "Card Numbers")?;
+    let id = input::value(text, input::after(text, card, "Card")?, "Card ID")?;
+
+    let (win_set, values_set) = input::split_once(text, numbers, "|", "Values set")?;
+    let winning = input::list(text, win_set, "winning number")?;
+    let values = input::list(text, values_set, "number")?;
+
+    Ok(Card { _id: id, winning, values })
+}
+
+fn update_copies(index: usize, copies: usize, scratchcards: &mut Vec<usize>) {
+    let new_els:i32 = ((index + copies + 1) as i32) - scratchcards.len() as i32;
+    if new_els > 0 {
+        for _ in 0..new_els {
+            scratchcards.push(1);
+        }
+    }
+
+    for i in 1..=copies {
+        scratchcards[index + i] += scratchcards[index];
+    }
+}
+
+pub struct Scratchcards;
+
+impl Solution for Scratchcards {
+    type Input = Vec<Card>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PART1: &'static str = "Total scratchcard winning points";
+    const PART2: &'static str = "Total scratchcards";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input::parse_lines(input, parse_card)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        //input.iter().for_each(|card| println!("Game {} = {}", card._id, card.calc_points()));
+
+        input.iter().map(|card| card.calc_points()).sum()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        let mut scratchcards: Vec<usize> = Vec::new();
+        for (index, card) in input.iter().enumerate() {
+            let matches = card.count_matches();
+            update_copies(index, matches, &mut scratchcards);
+        }
+
+        scratchcards.iter().sum::<usize>()
+    }
+}
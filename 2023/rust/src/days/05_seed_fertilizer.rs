@@ -0,0 +1,98 @@
+use crate::input::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::utils::range_set::RangeSet;
+
+use std::ops::Range;
+
+#[derive(Copy, Clone)]
+struct Mapping {
+    destination: u64,
+    source: u64,
+    length: u64
+}
+
+struct Category {
+    mappings: Vec<Mapping>
+}
+
+impl Category {
+    // the source ranges with where each one starts in the destination
+    fn pieces(&self) -> impl Iterator<Item = (Range<u64>, u64)> + '_ {
+        self.mappings.iter().map(|m| (m.source..m.source + m.length, m.destination))
+    }
+}
+
+// part 2 reads them as pairs of a start and a length
+fn parse_seeds(text: &str) -> ParseResult<Vec<u64>> {
+    let seeds: Vec<u64> = input::list(text, input::after(text, text, "seeds:")?, "seed")?;
+    if !seeds.len().is_multiple_of(2) {
+        return Err(ParseError::missing(text.trim_end(), "the length of the last seed range"));
+    }
+
+    Ok(seeds)
+}
+
+fn parse_mapping(text: &str) -> ParseResult<Mapping> {
+    let numbers = input::list(text, text, "range number")?;
+    let [destination, source, length] = input::exactly(text, text, numbers, "range numbers")?;
+
+    Ok(Mapping { destination, source, length })
+}
+
+// a "<from>-to-<to> map:" line followed by its mappings
+fn parse_category(block: &str) -> ParseResult<Category> {
+    let (header, mappings) = block.split_once('\n').unwrap_or((block, ""));
+    if !header.trim_end().ends_with("map:") {
+        return Err(ParseError::at(block, header, "expected '<category> map:'").on_line(1));
+    }
+    let mappings = input::parse_lines(mappings, parse_mapping).map_err(|e| e.shifted(1))?;
+
+    Ok(Category { mappings })
+}
+
+fn lowest_location(seeds: RangeSet<u64>, categories: &[Category]) -> u64 {
+    let locations = categories.iter().fold(seeds, |ranges, cat| ranges.map_pieces(cat.pieces()));
+
+    locations.min().unwrap_or(u64::MAX)
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    categories: Vec<Category>
+}
+
+pub struct SeedFertilizer;
+
+impl Solution for SeedFertilizer {
+    type Input = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const PART1: &'static str = "Lowest location number";
+    const PART2: &'static str = "Lowest location number";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let blocks = input::blocks(input);
+        let (_, seeds_text) = blocks.first().ok_or_else(|| ParseError::new(1, 1, "missing seeds"))?;
+        let seeds = parse_seeds(seeds_text).map_err(|e| e.on_line(1))?;
+
+        let categories = blocks[1..].iter()
+            .map(|(lines, block)| parse_category(block).map_err(|e| e.shifted(*lines)))
+            .collect::<ParseResult<_>>()?;
+
+        Ok(Almanac { seeds, categories })
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        let seeds = input.seeds.iter().map(|seed| *seed..seed + 1).collect();
+
+        lowest_location(RangeSet::from_ranges(seeds), &input.categories)
+    }
+
+    // seeds are ranges, which are mapped as a whole through every category
+    fn part2(input: &Self::Input) -> u64 {
+        let seeds = input.seeds.chunks(2).map(|x| x[0]..x[0] + x[1]).collect();
+
+        lowest_location(RangeSet::from_ranges(seeds), &input.categories)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::input::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+
+pub struct Race {
+    time: u64,
+    distance: u64
+}
+
+fn count_records(race: &Race) -> u32 {
+    let mut count = 0;
+    for x in 1..race.time {
+        if (race.time - x)*x > race.distance {
+            count+=1;
+        }
+    }
+
+    count
+}
+
+// the values of a "<label>: v1 v2 ..." line
+fn parse_races(times: &str, distances: &str) -> ParseResult<Vec<Race>> {
+    let race_times: Vec<u64> = input::after(times, times, "Time:")
+        .and_then(|row| input::list(times, row, "time"))
+        .map_err(|e| e.on_line(1))?;
+    let race_distances: Vec<u64> = input::after(distances, distances, "Distance:")
+        .and_then(|row| input::list(distances, row, "distance"))
+        .map_err(|e| e.on_line(2))?;
+
+    if race_times.len() != race_distances.len() {
+        return Err(ParseError::new(2, 1, format!("expected {} distances, found {}", race_times.len(), race_distances.len())));
+    }
+
+    Ok(race_times.into_iter()
+        .zip(race_distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect())
+}
+
+fn parse_single_race(times: &str, distances: &str) -> ParseResult<Race> {
+    let time = input::after(times, times, "Time:").map_err(|e| e.on_line(1))?.replace(' ', "");
+    let time = time.parse().map_err(|_| ParseError::new(1, 1, format!("invalid time '{}'", time)))?;
+    let distance = input::after(distances, distances, "Distance:").map_err(|e| e.on_line(2))?.replace(' ', "");
+    let distance = distance.parse().map_err(|_| ParseError::new(2, 1, format!("invalid distance '{}'", distance)))?;
+
+    Ok(Race {time, distance })
+}
+
+pub struct Races {
+    races: Vec<Race>,
+    single_race: Race
+}
+
+pub struct WaitForIt;
+
+impl Solution for WaitForIt {
+    type Input = Races;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const PART1: &'static str = "Multiplication of the number of ways the record is beat";
+    const PART2: &'static str = "Number of ways the record is beat";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let mut it = input.lines();
+
+        let times = it.next().ok_or_else(|| ParseError::new(1, 1, "missing race times"))?;
+        let distances = it.next().ok_or_else(|| ParseError::new(2, 1, "missing race distances"))?;
+
+        Ok(Races { races: parse_races(times, distances)?, single_race: parse_single_race(times, distances)? })
+    }
+
+    // multiple races
+    fn part1(input: &Self::Input) -> u32 {
+        input.races.iter().map(count_records).product()
+    }
+
+    // single race
+    fn part2(input: &Self::Input) -> u32 {
+        count_records(&input.single_race)
+    }
+}
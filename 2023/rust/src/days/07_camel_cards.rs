@@ -1,14 +1,10 @@
 use std::cmp::Ordering;
 
-mod utils;
-
-const RESOLVE_JOKERS: bool = true; // true -> part 2 ; false -> part 1
-
-static INPUT_FILE: &str = "./input/07_input.txt";
-//static INPUT_FILE: &str = "./input/07_input_test.txt";
+use crate::input::{self, ParseError, ParseResult};
+use crate::solution::Solution;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
-enum Card {
+pub enum Card {
     A = 13, 
     K = 12, 
     Q = 11,
@@ -59,9 +55,10 @@ enum Type {
 }
 
 #[derive(Debug, Eq)]
-struct Hand {
+pub struct Hand {
     cards: [Card; 5],
-    bid: u32
+    bid: u32,
+    jokers: bool // true -> part 2 ; false -> part 1
 }
 
 impl Ord for Hand {
@@ -79,7 +76,7 @@ impl Ord for Hand {
                     ord = self.cards[i].cmp(&other.cards[i]);
                     if ord != Ordering::Equal {
                         // handle case where J is the lowest card when the type is the same
-                        if RESOLVE_JOKERS {
+                        if self.jokers {
                             if self.cards[i] == Card::J {
                                 ord = Ordering::Less;
                             } else if other.cards[i] == Card::J {
@@ -126,7 +123,7 @@ impl Hand {
         }
 
         // resolve Jokers - omg... call the batman :)
-        if RESOLVE_JOKERS && cards_map[9] > 0 { // Jokers are strong with this one!
+        if self.jokers && cards_map[9] > 0 { // Jokers are strong with this one!
             let mut max: usize = 0;
             let mut max_card = 1;
             for (i, count) in cards_map.iter().enumerate() { // skipping J
@@ -185,42 +182,52 @@ impl Hand {
     }
 }
 
-fn parse_hand(text: &str) -> Hand {
-    let mut parts = text.split(' ');
-
-    let cards = parts.next().expect("No Hand found!").chars()
-        .map(|ch| Card::from_char(ch).unwrap())
-        .collect::<Vec<Card>>()
+fn parse_hand(text: &str) -> ParseResult<Hand> {
+    let [hand, bid] = input::fields(text, text, "fields, the hand and the bid")?;
+    let cards = hand.char_indices()
+        .map(|(i, ch)| Card::from_char(ch).map_err(|_| ParseError::at(text, &hand[i..], format!("invalid card '{}'", ch))))
+        .collect::<ParseResult<Vec<Card>>>()?
         .try_into()
-        .unwrap();
-    let bid = parts.next().expect("No Bid found!").parse().unwrap();
+        .map_err(|_| ParseError::at(text, hand, "a hand must have 5 cards"))?;
+    let bid = input::value(text, bid, "Bid")?;
 
-    Hand { cards, bid }
+    Ok(Hand { cards, bid, jokers: false })
 }
 
-fn main() {
-    if let Ok(lines) = utils::read_lines(INPUT_FILE) {
-        let mut hands = Vec::new();
+fn total_winnings(hands: &[Hand], jokers: bool) -> u32 {
+    let mut hands: Vec<Hand> = hands.iter().map(|hand| Hand { jokers, ..*hand }).collect();
 
-        for line in lines {
-            if let Ok(text) = line {
-                let hand = parse_hand(&text);
-                hands.push(hand);
-            }
-        }
+    hands.sort();
 
-        hands.sort();
+    let mut sum = 0;
 
-        let mut sum = 0;
+    for (i, hand) in hands.iter().enumerate() {
+        //println!("{:?} -> {:?}", hand, hand.get_type());
+        sum += (i + 1) as u32 * hand.bid;
+    }
 
-        for (i, hand) in hands.iter().enumerate() {
-            //println!("{:?} -> {:?}", hand, hand.get_type());
-            sum += (i + 1) as u32 * hand.bid;
-        }
+    sum
+}
+
+pub struct CamelCards;
+
+impl Solution for CamelCards {
+    type Input = Vec<Hand>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const PART1: &'static str = "Total winnings";
+    const PART2: &'static str = "Total winnings";
 
-        println!("[{}] Total winnings: {}", if RESOLVE_JOKERS { "Part 2" } else { "Part 1" }, sum);
-    } else {
-        eprintln!("Could not Camel Cards from {}", INPUT_FILE);
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input::parse_lines(input, parse_hand)
     }
 
+    fn part1(input: &Self::Input) -> u32 {
+        total_winnings(input, false)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        total_winnings(input, true)
+    }
 }
@@ -0,0 +1,171 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::input::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::utils::{cycle, math};
+
+#[derive(Debug, Clone)]
+pub struct MapEntry {
+    name: String,
+    left: String,
+    right: String
+}
+
+fn parse_map_entry(text: &str) -> ParseResult<MapEntry> {
+    let (name, paths) = input::split_once(text, text, "=", "map path")?;
+    let paths = input::enclosed(text, paths, '(', ')')?;
+    let (left, right) = input::split_once(text, paths, ",", "Right path")?;
+
+    Ok(MapEntry { name: name.to_string(), left: left.to_string(), right: right.to_string() })
+}
+
+// None when there is no AAA, or it never gets to ZZZ
+fn find_land_of_zzz(path: &[char], entries_map: &HashMap<String, MapEntry>) -> Option<u32> {
+    let mut step = entries_map.get("AAA")?;
+
+    // past this many steps a (node, position in the path) state comes back, and ZZZ never will
+    let limit = entries_map.len() * path.len();
+    for (steps, dir) in path.iter().cycle().enumerate().take(limit) {
+        step = match dir {
+            'L' => &entries_map[&step.left],
+            'R' => &entries_map[&step.right],
+            _ => unreachable!()
+        };
+
+        if step.name == "ZZZ" {
+            return Some(steps as u32 + 1);
+        }
+    }
+
+    None
+}
+
+/**
+ * A ghost always ends up walking in a loop, as its state is just the node and the position in the path.
+ * It is on a **Z node at the `prefix_hits`, which come before the loop, and then at every
+ * `loop_hits` + n * `period`.
+ */
+struct GhostWalk {
+    loop_start: u64,
+    period: u64,
+    prefix_hits: Vec<u64>,
+    loop_hits: Vec<u64>
+}
+
+impl GhostWalk {
+    fn is_on_z(&self, steps: u64) -> bool {
+        self.prefix_hits.contains(&steps) ||
+        (steps >= self.loop_start && self.loop_hits.iter().any(|hit| steps % self.period == hit % self.period))
+    }
+}
+
+fn walk_ghost(start: &MapEntry, path: &[char], entries_map: &HashMap<String, MapEntry>) -> GhostWalk {
+    // (node, position in the path)
+    let walk = cycle::find((start.name.as_str(), 0), |&(name, i)| {
+        let entry = &entries_map[name];
+        let next = match path[i] {
+            'L' => &entry.left,
+            'R' => &entry.right,
+            _ => unreachable!()
+        };
+        (next.as_str(), (i + 1) % path.len())
+    });
+
+    let loop_start = walk.prefix as u64;
+    let (prefix_hits, loop_hits) = walk.steps_where(|(name, _)| name.ends_with('Z')).into_iter()
+        .map(|steps| steps as u64)
+        .partition(|hit| *hit < loop_start);
+
+    GhostWalk { loop_start, period: walk.period as u64, prefix_hits, loop_hits }
+}
+
+fn find_land_of_zs(path: &[char], entries_map: &HashMap<String, MapEntry>) -> Option<u64> {
+    let walks = entries_map.values()
+        .filter(|e| e.name.ends_with('A'))
+        .map(|e| walk_ghost(e, path, entries_map))
+        .collect::<Vec<_>>();
+
+    // all the ghosts on a **Z node before some of them are looping
+    let mut first = walks.iter()
+        .flat_map(|w| w.prefix_hits.iter().copied())
+        .filter(|steps| walks.iter().all(|w| w.is_on_z(*steps)))
+        .min();
+
+    // otherwise every ghost is in its loop, where each of its hits is a congruence: folding them in one ghost
+    // at a time keeps only the residues, modulo the periods so far, that all those ghosts are on a **Z node at
+    let after_prefixes = walks.iter().map(|w| w.loop_start).max().unwrap_or(0) as i128;
+    let mut residues: HashSet<(i128, i128)> = HashSet::from([(0, 1)]);
+    for w in &walks {
+        residues = residues.iter()
+            .flat_map(|&residue| w.loop_hits.iter().filter_map(move |hit| math::crt(&[residue, (*hit as i128, w.period as i128)])))
+            .collect();
+    }
+
+    for (offset, modulus) in residues {
+        // the first solution where every ghost already is in its loop
+        let behind = (after_prefixes - offset).max(0);
+        let steps = (offset + (behind + modulus - 1) / modulus * modulus) as u64;
+        first = Some(first.map_or(steps, |f| f.min(steps)));
+    }
+
+    first
+}
+
+pub struct Network {
+    path: Vec<char>,
+    entries_map: HashMap<String, MapEntry>
+}
+
+pub struct HauntedWasteland;
+
+impl Solution for HauntedWasteland {
+    type Input = Network;
+    type Answer1 = u32;
+    type Answer2 = String;
+
+    const PART1: &'static str = "Steps required to reach ZZZ";
+    const PART2: &'static str = "Steps required to reach all **Z";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let mut lines = input.lines();
+        let mut entries_map = HashMap::new();
+
+        let path_text = lines.next().filter(|text| !text.is_empty()).ok_or_else(|| ParseError::new(1, 1, "missing input path"))?;
+        if let Some(col) = path_text.find(|ch| ch != 'L' && ch != 'R') {
+            return Err(ParseError::new(1, col + 1, "the path can only have 'L' and 'R'"));
+        }
+        let path = path_text.chars().collect();
+
+        let entries = input::parse_lines(input, |text| {
+            if text.contains('=') { parse_map_entry(text).map(Some) } else { Ok(None) }
+        })?;
+
+        for map_entry in entries.iter().flatten() {
+            entries_map.insert(map_entry.name.clone(), map_entry.clone());
+        }
+
+        // every path must lead to a known entry
+        for (i, map_entry) in entries.iter().enumerate() {
+            if let Some(map_entry) = map_entry {
+                for next in [&map_entry.left, &map_entry.right] {
+                    if !entries_map.contains_key(next) {
+                        return Err(ParseError::new(i + 1, 1, format!("unknown map entry '{}'", next)));
+                    }
+                }
+            }
+        }
+
+        Ok(Network { path, entries_map })
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        find_land_of_zzz(&input.path, &input.entries_map).unwrap_or(0)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        match find_land_of_zs(&input.path, &input.entries_map) {
+            Some(steps) => steps.to_string(),
+            None => "the ghosts are never all on **Z nodes at the same time".to_string()
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::input::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+
+fn extrapolate_next(sequence: &[i32]) -> i32 {
+    let mut reduced = false;
+    let mut n = *sequence.last().unwrap();
+    
+    let mut seq = sequence.to_vec();
+    
+    while !reduced {
+        let mut sub_seq = Vec::new();
+        for i in 1..seq.len() {
+            sub_seq.push(seq[i] - seq[i - 1]);
+        }
+        reduced = sub_seq.iter().all(|i| *i == 0);
+        n += sub_seq.last().unwrap();
+
+        seq = sub_seq;
+    }
+    
+    n
+}
+
+fn extrapolate_prev(sequence: &[i32]) -> i32 {
+    let mut reduced = false;
+    
+    let mut seq = sequence.to_vec();
+    let mut values = Vec::new();
+    
+    while !reduced {
+        let mut sub_seq = Vec::new();
+        for i in 1..seq.len() {
+            sub_seq.push(seq[i] - seq[i - 1]);
+        }
+        reduced = sub_seq.iter().all(|i| *i == 0);
+
+        values.push(*sub_seq.first().unwrap());
+
+        seq = sub_seq;
+    }
+
+    let mut n = 0;
+    for v in values.iter().rev() {
+        n = v - n;
+    }
+  
+    *sequence.first().unwrap() - n
+}
+
+
+pub struct MirageMaintenance;
+
+impl Solution for MirageMaintenance {
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    const PART1: &'static str = "Sum of next extrapolated values";
+    const PART2: &'static str = "Sum of prev extrapolated values";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input::parse_lines(input, |text| {
+            let sequence: Vec<i32> = input::list(text, text, "value")?;
+
+            if sequence.len() < 2 {
+                return Err(ParseError::missing(text, "values, a sequence needs at least 2"));
+            }
+
+            Ok(sequence)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        input.iter().map(|sequence| extrapolate_next(sequence)).sum()
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        input.iter().map(|sequence| extrapolate_prev(sequence)).sum()
+    }
+}
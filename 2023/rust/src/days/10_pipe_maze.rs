@@ -0,0 +1,136 @@
+use crate::input::{ParseError, ParseResult};
+use crate::render::{Color, Frame};
+use crate::solution::Solution;
+use crate::utils::direction::Direction;
+use crate::utils::geometry::{Location, Point, Polygon};
+use crate::utils::grid::Grid;
+
+// the two sides a pipe connects
+fn pipe_openings(pipe: char) -> Option<[Direction; 2]> {
+    match pipe {
+        '|' => Some([Direction::Up, Direction::Down]),
+        '-' => Some([Direction::Left, Direction::Right]),
+        'L' => Some([Direction::Up, Direction::Right]),
+        'J' => Some([Direction::Up, Direction::Left]),
+        '7' => Some([Direction::Down, Direction::Left]),
+        'F' => Some([Direction::Down, Direction::Right]),
+        _ => None
+    }
+}
+
+// whether moving in `dir` from `pos` enters a pipe that connects back
+fn connects(pos: (usize, usize), dir: Direction, ground: &Grid<char>) -> bool {
+    ground.step(pos, dir)
+        .and_then(|next| pipe_openings(ground[next]))
+        .is_some_and(|openings| openings.contains(&dir.opposite()))
+}
+
+fn at((x, y): (usize, usize), message: impl Into<String>) -> ParseError {
+    ParseError::new(y + 1, x + 1, message)
+}
+
+// The loop tiles in the order they are walked, from the start, or where it is broken
+fn find_loop(ground: &Grid<char>) -> ParseResult<Vec<(usize, usize)>> {
+    let mut starts = ground.find_all(&'S');
+    let (Some(start), None) = (starts.next(), starts.next()) else {
+        return Err(ParseError::new(1, 1, "the maze must have exactly one starting point 'S'"));
+    };
+
+    // find valid path to start walking
+    let mut dir = [Direction::Right, Direction::Left, Direction::Up, Direction::Down].into_iter()
+        .find(|dir| connects(start, *dir, ground))
+        .ok_or_else(|| at(start, "the starting point is not connected to any pipe"))?;
+
+    let mut path = vec![start];
+    let mut pos = start;
+
+    loop {
+        let next = ground.step(pos, dir).ok_or_else(|| at(pos, "the loop runs off the maze"))?;
+        if ground[next] == 'S' {
+            return Ok(path);
+        }
+
+        // leave the pipe through the opening we did not come in from
+        let openings = match pipe_openings(ground[next]) {
+            Some(openings) if openings.contains(&dir.opposite()) => openings,
+            _ => return Err(at(next, format!("the loop is broken, '{}' does not connect back", ground[next])))
+        };
+        dir = if openings[0] == dir.opposite() { openings[1] } else { openings[0] };
+
+        path.push(next);
+        pos = next;
+    }
+}
+
+// The loop as a polygon through the middle of its tiles, with only the bends as vertices
+fn loop_polygon(ground: &Grid<char>, path: &[(usize, usize)]) -> Polygon<i64> {
+    let corners = path.iter()
+        .filter(|pos| !"|-".contains(ground[**pos]))
+        .map(|&(x, y)| Point::new(x as i64, y as i64))
+        .collect();
+
+    Polygon::new(corners)
+}
+
+pub struct Maze {
+    ground: Grid<char>,
+    // the loop tiles in the order they are walked, from the start
+    path: Vec<(usize, usize)>
+}
+
+pub struct PipeMaze;
+
+impl Solution for PipeMaze {
+    type Input = Maze;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PART1: &'static str = "Steps to de farthest point";
+    const PART2: &'static str = "Loop area";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let ground = Grid::parse(input, |ch| "|-LJ7F.S".contains(ch))?;
+        let path = find_loop(&ground)?;
+
+        Ok(Maze { ground, path })
+    }
+
+    fn part1(maze: &Self::Input) -> usize {
+        maze.path.len() / 2
+    }
+
+    fn part2(maze: &Self::Input) -> usize {
+        // the tiles enclosed are the points strictly inside the polygon, counted with Pick's theorem
+        loop_polygon(&maze.ground, &maze.path).interior_points() as usize
+    }
+
+    // the loop in yellow, the tiles it encloses in green
+    fn render(maze: &Self::Input) -> Vec<Frame> {
+        let Maze { ground, path } = maze;
+        let polygon = loop_polygon(ground, path);
+        let inside = ground.positions().filter(|&(x, y)| polygon.locate(Point::new(x as i64, y as i64)) == Location::Inside);
+
+        let frame = Frame::new("loop", ground, |ch| if *ch == '.' { Color::BLACK } else { Color::DARK_GREY })
+            .overlay(path.iter().copied(), Color::YELLOW)
+            .overlay(inside, Color::GREEN)
+            .overlay(path.first().copied(), Color::RED);
+
+        vec![frame]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{agree, Shrink};
+
+    #[test]
+    fn picks_theorem_agrees_with_point_in_polygon() {
+        agree::<PipeMaze, usize>(10, &[5, 12, 30], 20, Shrink::Size,
+            PipeMaze::part2,
+            |maze| {
+                let polygon = loop_polygon(&maze.ground, &maze.path);
+                maze.ground.positions().filter(|&(x, y)| polygon.locate(Point::new(x as i64, y as i64)) == Location::Inside).count()
+            });
+    }
+}
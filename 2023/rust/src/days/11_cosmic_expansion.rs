@@ -0,0 +1,108 @@
+use crate::input::ParseResult;
+use crate::render::{Color, Frame};
+use crate::solution::Solution;
+use crate::utils::grid::Grid;
+
+struct Galaxy {
+    x: usize,
+    y: usize
+}
+
+// naive solution where we the image was expanded in size
+fn _expand_universe(image: &Grid<char>) -> Grid<char> {
+    let rows_exp = find_empty_rows(image);
+    let cols_exp = find_empty_columns(image);
+
+    let mut data = vec![];
+    for (y, row) in image.rows().enumerate() {
+        let mut expanded_row = vec![];
+        for (x, ch) in row.iter().enumerate() {
+            expanded_row.push(*ch);
+            if cols_exp.contains(&x) {
+                expanded_row.push('.');
+            }
+        }
+        if rows_exp.contains(&y) {
+            data.extend(expanded_row.iter());
+        }
+        data.extend(expanded_row);
+    }
+
+    Grid::from_vec(image.width() + cols_exp.len(), image.height() + rows_exp.len(), data)
+}
+
+fn find_empty_rows(image: &Grid<char>) -> Vec<usize> {
+    image.rows().enumerate()
+        .filter(|(_, row)| row.iter().all(|c| *c == '.'))
+        .map(|(i, _)| i)
+        .rev()
+        .collect()
+}
+
+fn find_empty_columns(image: &Grid<char>) -> Vec<usize> {
+    (0..image.width())
+        .filter(|col| image.column(*col).all(|c| *c == '.'))
+        .rev()
+        .collect()
+}
+
+
+fn sum_galaxy_pair_lengths(image: &Grid<char>, expand_factor: usize) -> u64 {
+    let mut galaxy_pairs = Vec::new();
+
+    let empty_rows = find_empty_rows(image);
+    let empty_cols = find_empty_columns(image);
+
+    // collect galaxy pairs, in reading order so the second galaxy is never above the first
+    let galaxies: Vec<Galaxy> = image.find_all(&'#').map(|(x, y)| Galaxy { x, y }).collect();
+    for (i, g1) in galaxies.iter().enumerate() {
+        for g2 in &galaxies[i + 1..] {
+            galaxy_pairs.push((g1, g2));
+        }
+    }
+
+    // find shortest length
+    galaxy_pairs.iter().map(|(g1, g2)| {
+        let y_diff: u64 = (g2.y.abs_diff(g1.y) + (empty_rows.iter().filter(|row| **row > g1.y && **row < g2.y).count() * (expand_factor - 1))) as u64;
+        let x_diff: u64 = (g2.x.abs_diff(g1.x) + (empty_cols.iter().filter(|col| **col > g1.x.min(g2.x) && **col < g1.x.max(g2.x)).count() * (expand_factor - 1))) as u64;
+        y_diff + x_diff
+    }
+    ).sum::<u64>()
+
+}
+
+pub struct CosmicExpansion;
+
+impl Solution for CosmicExpansion {
+    type Input = Grid<char>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const PART1: &'static str = "[Expansion factor = 2] Sum of shortest path lengths";
+    const PART2: &'static str = "[Expansion factor = 1000000] Sum of shortest path lengths";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        Grid::parse(input, |ch| ch == '.' || ch == '#')
+    }
+
+    fn part1(image: &Self::Input) -> u64 {
+        sum_galaxy_pair_lengths(image, 2)
+    }
+
+    fn part2(image: &Self::Input) -> u64 {
+        sum_galaxy_pair_lengths(image, 1000000)
+    }
+
+    // the galaxies in white, the rows and columns that expand in blue
+    fn render(image: &Self::Input) -> Vec<Frame> {
+        let empty_rows = find_empty_rows(image);
+        let empty_cols = find_empty_columns(image);
+        let expanding = image.positions().filter(|(x, y)| empty_rows.contains(y) || empty_cols.contains(x));
+
+        let frame = Frame::new("universe", image, |_| Color::BLACK)
+            .overlay(expanding, Color::BLUE)
+            .overlay(image.find_all(&'#'), Color::WHITE);
+
+        vec![frame]
+    }
+}
@@ -0,0 +1,281 @@
+use crate::input::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::utils::memo::Memo;
+
+use std::env;
+use rayon::prelude::*;
+
+#[derive(Debug)]
+pub struct Record {
+    pattern: String,
+    damaged_groups: Vec<u32>
+}
+
+fn parse_record(text: &str) -> ParseResult<Record> {
+    let [pattern, groups] = input::fields(text, text, "fields, the springs and their damaged groups")?;
+    if let Some(col) = pattern.find(|ch| !".#?".contains(ch)) {
+        return Err(ParseError::at(text, &pattern[col..], "springs can only be '.', '#' or '?'"));
+    }
+    let damaged_groups = input::separated(text, groups, ',', "group size")?;
+    
+    Ok(Record { pattern: pattern.to_owned(), damaged_groups })
+}
+
+// naive solution -> brute force
+fn count_arrangements_bf(pattern: String, groups: &[u32]) -> u64 {
+    let mut sum = 0;
+    let s = pattern.clone();
+    
+    match s.find('?') {
+        Some(_) => {
+            sum += count_arrangements_bf(s.clone().replacen('?', "#", 1), groups);
+            sum += count_arrangements_bf(s.clone().replacen('?', ".", 1), groups);
+            sum
+        }
+        None => if pattern_matches(&s, groups) {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+fn pattern_matches(pattern: &str, groups: &[u32]) -> bool {
+    let mut count = 0;
+    let mut g = 0;
+
+    for (i, c) in pattern.chars().enumerate() {
+        if c == '#' {
+            if g == groups.len() {
+                return false;
+            }
+            count += 1;
+        }
+        if (c == '.' || i + 1 == pattern.len()) && count > 0 {
+            if g == groups.len() || count != groups[g] {
+                return false;
+            }
+
+            g += 1;
+            count = 0;
+        }
+    }
+
+    //println!("{}", pattern);
+    g >= groups.len()
+}
+
+fn count_arrangements_rec(pattern: &str, groups: &[u32]) -> u64 {
+    arrangements_from(pattern.as_bytes(), groups, (0, 0), &mut Memo::new())
+}
+
+// The arrangements of the springs from pattern_index on, with the groups from group_index on still to place
+fn arrangements_from(
+    pattern: &[u8],
+    groups: &[u32],
+    (pattern_index, group_index): (usize, usize),
+    memo: &mut Memo<(usize, usize), u64>) -> u64 {
+
+    if pattern_index >= pattern.len() {
+        return (group_index == groups.len()) as u64;
+    }
+
+    if group_index == groups.len() {
+        return !pattern[pattern_index..].contains(&b'#') as u64;
+    }
+
+    if let Some(result) = memo.get(&(pattern_index, group_index)) {
+        return result;
+    }
+
+    let mut result: u64 = 0;
+
+    let first_ch = pattern[pattern_index];
+
+    if first_ch == b'.' || first_ch == b'?' {
+        result += arrangements_from(pattern, groups, (pattern_index + 1, group_index), memo);
+    }
+
+    // the group fits here when it is not cut by a '.' and not followed by a '#', and the next one starts after a '.'
+    let end = pattern_index + groups[group_index] as usize;
+    if (first_ch == b'#' || first_ch == b'?')
+        && end <= pattern.len() && !pattern[pattern_index..end].contains(&b'.') && pattern.get(end) != Some(&b'#') {
+            result += arrangements_from(pattern, groups, (end + 1, group_index + 1), memo);
+        }
+
+    memo.insert((pattern_index, group_index), result)
+}
+
+fn count_arrangements_imp(mut pattern: String, groups: &[u32]) -> u64 {
+    let mut sum = 0;
+
+    pattern.push('.'); // HACK
+
+    let mut patterns = vec![&pattern[..]];
+
+    for (i, n) in groups.iter().enumerate() {
+        let mut temp = Vec::new();
+        for pattern in patterns {
+            if !pattern.is_empty() {
+                temp.append(&mut find_next_possible_arrangements(pattern, *n));
+            }
+        }
+
+        if i == &groups.len() - 1 {
+            for p in &temp {
+                if !(*p).contains('#') {
+                    sum += 1;
+                }
+            }
+            //sum = temp.len();
+        }
+
+        patterns = temp;
+    }
+
+    sum
+}
+
+fn find_next_possible_arrangements(sub_pattern: &str, n: u32) -> Vec<&str> {    
+    let mut sub: Vec<&str> = Vec::new();
+    
+    let mut pattern_queue = vec![sub_pattern];
+    
+    while !pattern_queue.is_empty() {
+        let mut pounds = 0;
+        let mut fixed = false;
+        let pattern: &str = pattern_queue.pop().unwrap();
+
+        for (i, ch) in pattern.char_indices() {
+            if pounds == n && (ch == '.' || ch == '?') {
+                sub.push(&pattern[(i + 1)..]);
+                break;
+            }
+
+            if ch == '.' && pounds > 0 && pounds < n {
+                pattern_queue = vec![];
+                pounds = 0;
+                if fixed {
+                    break;
+                }
+            }
+
+            if pounds == n && ch == '#' {
+                // invalid
+                break;
+            }
+
+            if ch == '?' {
+                pounds += 1;
+                if !fixed && pounds == 1 {
+                    let next_pattern = &pattern[(i + 1)..];
+                    if !pattern_queue.contains(&next_pattern) {
+                        pattern_queue.push(next_pattern);
+                    }
+                }
+            } else if ch == '#' {
+                pounds += 1;
+                fixed = true;
+            }
+        }
+
+    }
+
+    sub
+}
+
+fn unfold_record(record: &Record) -> Record {
+    let groups = record.damaged_groups.repeat(5);
+    let mut pattern = String::with_capacity(record.pattern.capacity() * 5);
+    for i in 0..5 {
+        pattern.push_str(&record.pattern);
+        if i < 4 {
+            pattern.push('?');
+        }
+    }
+    
+    Record { pattern, damaged_groups: groups }
+}
+
+// records are counted in parallel, the sum does not depend on the order
+fn sum_arrangements(records: &[Record], unfold: bool, count: fn(&Record) -> u64) -> u64 {
+    records.par_iter().map(|r| {
+        if unfold {
+            count(&unfold_record(r))
+        } else {
+            count(r)
+        }
+    }).sum()
+}
+
+// the runner owns the command line, so the algorithm is picked through the environment
+fn arrangements_counter() -> fn(&Record) -> u64 {
+    let alg = env::var("AOC_D12_ALG").unwrap_or("REC".to_string());
+
+    match alg.as_str() {
+        "BRUTE" => |r| count_arrangements_bf(r.pattern.clone(), &r.damaged_groups),
+        "IMP" => |r| count_arrangements_imp(r.pattern.clone(), &r.damaged_groups),
+        "REC" => |r| count_arrangements_rec(r.pattern.as_str(), &r.damaged_groups),
+        _ => |r| count_arrangements_rec(r.pattern.as_str(), &r.damaged_groups)
+    }
+}
+
+pub struct HotSprings;
+
+impl Solution for HotSprings {
+    type Input = Vec<Record>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const PART1: &'static str = "Sum of all operational spring arrangements";
+    const PART2: &'static str = "Sum of all unfolded operational spring arrangements";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input::parse_lines(input, parse_record)
+    }
+
+    fn part1(records: &Self::Input) -> u64 {
+        sum_arrangements(records, false, arrangements_counter())
+    }
+
+    fn part2(records: &Self::Input) -> u64 {
+        sum_arrangements(records, true, arrangements_counter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{agree, Shrink};
+
+    fn sum_with(records: &[Record], count: fn(&Record) -> u64) -> u64 {
+        sum_arrangements(records, false, count)
+    }
+
+    #[test]
+    fn brute_force_agrees_with_recursion() {
+        agree::<HotSprings, u64>(12, &[1, 5, 20, 60], 20, Shrink::Lines,
+            |records| sum_with(records, |r| count_arrangements_bf(r.pattern.clone(), &r.damaged_groups)),
+            |records| sum_with(records, |r| count_arrangements_rec(&r.pattern, &r.damaged_groups)));
+    }
+
+    // the improved brute force still goes through every arrangement, so only on the folded records
+    #[test]
+    fn improved_agrees_with_recursion() {
+        agree::<HotSprings, u64>(12, &[1, 5, 20, 60], 20, Shrink::Lines,
+            |records| sum_with(records, |r| count_arrangements_imp(r.pattern.clone(), &r.damaged_groups)),
+            |records| sum_with(records, |r| count_arrangements_rec(&r.pattern, &r.damaged_groups)));
+    }
+
+    #[test]
+    fn unfolded_record_is_counted_once_per_position() {
+        let record = unfold_record(&parse_record("?###???????? 3,2,1").unwrap());
+        let mut memo = Memo::new();
+
+        assert_eq!(arrangements_from(record.pattern.as_bytes(), &record.damaged_groups, (0, 0), &mut memo), 506250);
+
+        let stats = memo.stats();
+        assert!(stats.misses as usize <= (record.pattern.len() + 1) * (record.damaged_groups.len() + 1));
+        assert!(stats.hits > 0);
+    }
+}
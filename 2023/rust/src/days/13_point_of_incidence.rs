@@ -0,0 +1,117 @@
+use crate::input::{self, ParseResult};
+use crate::solution::Solution;
+use crate::utils::grid::Grid;
+
+fn process_chunk(chunk: &Grid<char>, smudge: bool) -> u32 {
+    if smudge {
+        let mut count = process_chunk_row(chunk, true);
+        if count.1 {
+            count.0 * 100
+        } else {
+            count = process_chunk_row(&chunk.transposed(), true);
+            count.0
+        }
+    } else {
+        let mut count = process_chunk_row(chunk, smudge);
+        if count.0 > 0 {
+            return count.0 * 100;
+        }
+
+        count = process_chunk_row(&chunk.transposed(), smudge);
+        count.0
+    }
+}
+
+fn compare_rows(row1: &[char], row2: &[char], smudge: bool) -> (bool, bool) {
+    let mut count_diff = 0;
+
+    if smudge {
+        for i in 0..row1.len() {
+            if row1[i] != row2[i] {
+                count_diff += 1;
+            }
+        }
+
+        if count_diff < 2 {
+            (true, count_diff == 1)
+        } else {
+            (false, false)
+        }
+    } else {
+        (row1.eq(row2), false)
+    }
+}
+
+fn process_chunk_row(chunk: &Grid<char>, smudge: bool) -> (u32, bool) {
+    let height = chunk.height();
+    for row in 1..height {
+        let row1 = chunk.row(row - 1);
+        let row2 = chunk.row(row);
+        let (eq, mut found_smudge) = compare_rows(row1, row2, smudge);
+        if eq {
+            if row == 1 || row == height - 1 {
+                if smudge {
+                    if found_smudge {
+                        return (row as u32, found_smudge);
+                    } else {
+                        continue;
+                    }
+                } else {
+                    return (row as u32, false);
+                }
+            }
+            let mut up = (row - 2) as i32;
+            let mut down = row + 1;
+            let mut reflect = true;
+            while up >= 0 && down < height {
+                let r1 = chunk.row(up as usize);
+                let r2 = chunk.row(down);
+                let (eq, _found_smudge) = compare_rows(r1, r2, smudge && !found_smudge);
+                if _found_smudge {
+                    found_smudge = true;
+                }
+                if !eq {
+                    reflect = false;
+                    break;
+                }
+
+                up -= 1;
+                down += 1;
+            }
+
+            if reflect {
+                if smudge {
+                    if found_smudge {
+                        return (row as u32, found_smudge);
+                    }
+                } else {
+                    return (row as u32, false);
+                }
+            }
+        }
+    }
+    (0, false)
+}
+
+pub struct PointOfIncidence;
+
+impl Solution for PointOfIncidence {
+    type Input = Vec<Grid<char>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const PART1: &'static str = "Sum of all reflection patterns";
+    const PART2: &'static str = "Sum of all reflection patterns after fixing the smudge";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input::parse_blocks(input, |block| Grid::parse(block, |ch| ch == '.' || ch == '#'))
+    }
+
+    fn part1(chunks: &Self::Input) -> u32 {
+        chunks.iter().map(|chunk| process_chunk(chunk, false)).sum()
+    }
+
+    fn part2(chunks: &Self::Input) -> u32 {
+        chunks.iter().map(|chunk| process_chunk(chunk, true)).sum()
+    }
+}
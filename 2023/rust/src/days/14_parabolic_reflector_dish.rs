@@ -0,0 +1,226 @@
+use crate::input::ParseResult;
+use crate::render::{Color, Frame};
+use crate::solution::Solution;
+use crate::utils::cycle;
+use crate::utils::grid::Grid;
+
+use std::fmt;
+
+const SPIN_CYCLES: usize = 1000000000;
+const ANIMATED_CYCLES: usize = 25;
+
+type Tilt = fn(&mut ParabolicDish);
+
+// the tilts of a spin cycle, in order
+const SPIN: [(&str, Tilt); 4] = [
+    ("north", ParabolicDish::tilt_north),
+    ("west", ParabolicDish::tilt_west),
+    ("south", ParabolicDish::tilt_south),
+    ("east", ParabolicDish::tilt_east)
+];
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ParabolicDish {
+    grid: Grid<char>
+}
+
+impl ParabolicDish {
+    fn tilt_north(&mut self) {
+        for col in 0..self.grid.width() {
+            for row in 0..self.grid.height() {
+                let ch = self.grid[(col, row)];
+                if ch == 'O' && row > 0 {
+                    let mut i: i32 = (row - 1) as i32;
+                    let mut first_empty = -1;
+                    
+                    while i >= 0 {
+                        let cch = self.grid[(col, i as usize)];
+                        if cch != '.' {
+                            break;
+                        } else {
+                            first_empty = i;
+                        }
+
+                        i -= 1;
+                    }
+
+                    if first_empty >= 0 {
+                        self.grid[(col, first_empty as usize)] = 'O';
+                        self.grid[(col, row)] = '.';
+                    }
+                }
+            }
+        }
+    }
+
+    fn tilt_south(&mut self) {
+        for col in 0..self.grid.width() {
+            for row in (0..self.grid.height()).rev() {
+                let ch = self.grid[(col, row)];
+                if ch == 'O' && row < self.grid.height() - 1 {
+                    let mut i = row + 1;
+                    let mut first_empty: i32 = -1;
+                    
+                    while i < self.grid.height() {
+                        let cch = self.grid[(col, i)];
+                        if cch != '.' {
+                            break;
+                        } else {
+                            first_empty = i as i32;
+                        }
+
+                        i += 1;
+                    }
+
+                    if first_empty >= 0 {
+                        self.grid[(col, first_empty as usize)] = 'O';
+                        self.grid[(col, row)] = '.';
+                    }
+                }
+            }
+        }
+    }
+
+    fn tilt_east(&mut self) { // right
+        for row in 0..self.grid.height() {
+            for col in (0..self.grid.width()).rev() {
+                let ch = self.grid[(col, row)];
+                if ch == 'O' && col < self.grid.width() - 1 {
+                    let mut i = col + 1;
+                    let mut first_empty: i32 = -1;
+                    
+                    while i < self.grid.width() {
+                        let cch = self.grid[(i, row)];
+                        if cch != '.' {
+                            break;
+                        } else {
+                            first_empty = i as i32;
+                        }
+
+                        i += 1;
+                    }
+
+                    if first_empty >= 0 {
+                        self.grid[(first_empty as usize, row)] = 'O';
+                        self.grid[(col, row)] = '.';
+                    }
+                }
+            }
+        }
+    }
+
+    fn tilt_west(&mut self) { // right
+        for row in 0..self.grid.height() {
+            for col in 0..self.grid.width() {
+                let ch = self.grid[(col, row)];
+                if ch == 'O' && col > 0 {
+                    let mut i: i32 = (col - 1) as i32;
+                    let mut first_empty: i32 = -1;
+                    
+                    while i >= 0 {
+                        let cch = self.grid[(i as usize, row)];
+                        if cch != '.' {
+                            break;
+                        } else {
+                            first_empty = i;
+                        }
+
+                        i -= 1;
+                    }
+
+                    if first_empty >= 0 {
+                        self.grid[(first_empty as usize, row)] = 'O';
+                        self.grid[(col, row)] = '.';
+                    }
+                }
+            }
+        }
+    }
+
+
+    // one spin cycle: north, west, south and east
+    fn spin(&mut self) {
+        for (_, tilt) in SPIN {
+            tilt(self);
+        }
+    }
+
+    fn frame(&self, name: &str) -> Frame {
+        Frame::new(name, &self.grid, |ch| match ch {
+            'O' => Color::WHITE,
+            '#' => Color::GREY,
+            _ => Color::BLACK
+        })
+    }
+
+    fn north_load(&self) -> u32 {
+        let height = self.grid.height();
+        self.grid.find_all(&'O').map(|(_, row)| (height - row) as u32).sum()
+    }
+
+}
+
+impl fmt::Display for ParabolicDish {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+// the dish after all the SPIN_CYCLES, which end up going round in a loop
+fn after_spin_cycles(input: &ParabolicDish) -> ParabolicDish {
+    let spins = cycle::find(input.clone(), |dish| {
+        let mut dish = dish.clone();
+        dish.spin();
+        dish
+    });
+
+    spins.state_at(SPIN_CYCLES).clone()
+}
+
+pub struct ParabolicReflectorDish;
+
+impl Solution for ParabolicReflectorDish {
+    type Input = ParabolicDish;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const PART1: &'static str = "Total load of north support beams";
+    const PART2: &'static str = "Total load of north support beams after 1000000000 cycles";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let grid = Grid::parse(input, |ch| ".#O".contains(ch))?;
+
+        Ok(ParabolicDish { grid })
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        let mut dish = input.clone();
+        dish.tilt_north();
+        dish.north_load()
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        after_spin_cycles(input).north_load()
+    }
+
+    fn render(input: &Self::Input) -> Vec<Frame> {
+        let mut tilted = input.clone();
+        tilted.tilt_north();
+
+        vec![tilted.frame("tilted_north"), after_spin_cycles(input).frame("spun")]
+    }
+
+    // the first spin cycles, one frame per tilt
+    fn animate(input: &Self::Input) -> Vec<Frame> {
+        let mut dish = input.clone();
+        let mut frames = vec![dish.frame("start")];
+        for cycle in 1..=ANIMATED_CYCLES {
+            for (name, tilt) in SPIN {
+                tilt(&mut dish);
+                frames.push(dish.frame(&format!("cycle_{}_{}", cycle, name)));
+            }
+        }
+
+        frames
+    }
+}
@@ -0,0 +1,82 @@
+use crate::input::{ParseError, ParseResult};
+use crate::solution::Solution;
+
+fn hash(value: &str) -> u32 {
+    value.chars().fold(0, |curr, ch| ((curr + ch as u32) * 17) % 256 )
+}
+
+const fn vec_init() -> Vec<&'static str> {
+    Vec::new()
+}
+
+pub struct LensLibrary;
+
+impl Solution for LensLibrary {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    const PART1: &'static str = "Sum of all step hashes";
+    const PART2: &'static str = "Focusing power of the resulting lens configuration";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let sequence = input.trim();
+        let steps: Vec<String> = sequence.split(",").map(String::from).collect();
+
+        // every step is either <label>- or <label>=<focal length>
+        let mut column = 1;
+        for step in &steps {
+            let valid = match step.split_once("=") {
+                Some((label, focal)) => !label.is_empty() && focal.parse::<usize>().is_ok(),
+                None => step.len() > 1 && step.ends_with("-")
+            };
+            if !valid {
+                return Err(ParseError::new(1, column, format!("invalid step '{}'", step)));
+            }
+            column += step.len() + 1;
+        }
+
+        Ok(steps)
+    }
+
+    fn part1(steps: &Self::Input) -> u32 {
+        steps.iter().map(|step| hash(step)).sum()
+    }
+
+    fn part2(steps: &Self::Input) -> usize {
+        let mut sum2: usize = 0;
+
+        const VAL: Vec<&str> = vec_init();
+        let mut boxes: [Vec<&str>; 256] = [VAL; 256];
+
+        for step in steps {
+            if step.ends_with("-") {
+                let label = &step[0..(step.len() - 1)];
+                let hashed = hash(label);
+                let v = &mut boxes[hashed as usize];
+                v.retain(|st| !st.starts_with(label));
+            } else {
+                let mut it = step.split("=");
+                let label = it.next().unwrap();
+                let hashed = hash(label);
+                let v = &mut boxes[hashed as usize];
+                if let Some(pos) = v.iter().position(|st| st.starts_with(label)) {
+                    v[pos] = step;
+                } else {
+                    v.push(step);
+                }
+            }
+        }
+
+        for (i, v) in boxes.iter().enumerate() {
+            for (j, lens) in v.iter().enumerate() {
+                let mut it = lens.split("=");
+                it.next();
+                let focal: usize = it.next().unwrap().parse().unwrap();
+                sum2 += (1 + i) * (j + 1) * focal;
+            }
+        }
+
+        sum2
+    }
+}
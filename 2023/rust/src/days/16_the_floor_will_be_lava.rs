@@ -0,0 +1,106 @@
+use crate::animate;
+use crate::input::ParseResult;
+use crate::render::{Color, Frame};
+use crate::solution::Solution;
+use crate::utils::direction::Direction;
+use crate::utils::grid::Grid;
+
+use indexmap::IndexMap;
+use rayon::prelude::*;
+
+fn get_energized_tiles(cont: &Grid<char>, mut pos: (usize, usize), mut dir: Direction, visited: &mut IndexMap<(usize, usize), Direction>) {
+    loop {
+        if visited.get(&pos) == Some(&dir) {
+            break;
+        }
+
+        let ch = cont[pos];
+        visited.insert(pos, dir);
+        
+        // a splitter hit on its flat side sends the beam both ways
+        let splits = (ch == '|' && dir.is_horizontal()) || (ch == '-' && !dir.is_horizontal());
+        if splits {
+            for split_dir in [dir.turn_left(), dir.turn_right()] {
+                if let Some(next) = cont.step(pos, split_dir) {
+                    get_energized_tiles(cont, next, split_dir, visited);
+                }
+            }
+            break;
+        }
+
+        dir = dir.reflect(ch);
+
+        match cont.step(pos, dir) {
+            Some(next) => pos = next,
+            None => break
+        }
+    }
+}
+
+// how many tiles a beam entering at `pos` going `dir` energizes
+fn energized_count(contraption: &Grid<char>, pos: (usize, usize), dir: Direction) -> usize {
+    let mut visited: IndexMap<(usize, usize), Direction> = IndexMap::new();
+    get_energized_tiles(contraption, pos, dir, &mut visited);
+
+    visited.len()
+}
+
+pub struct TheFloorWillBeLava;
+
+impl Solution for TheFloorWillBeLava {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PART1: &'static str = "Sum of energized tiles";
+    const PART2: &'static str = "Sum of energized tiles in optimal configuration";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        Grid::parse(input, |ch| ".|-/\\".contains(ch))
+    }
+
+    fn part1(contraption: &Self::Input) -> usize {
+        energized_count(contraption, (0, 0), Direction::Right)
+    }
+
+    // every beam is traced on its own, so the edge tiles are tried in parallel
+    fn part2(contraption: &Self::Input) -> usize {
+        let (width, height) = (contraption.width(), contraption.height());
+
+        let starts: Vec<((usize, usize), Direction)> = (0..width)
+            .flat_map(|x| [((x, 0), Direction::Down), ((x, height - 1), Direction::Up)])
+            .chain((0..height).flat_map(|y| [((0, y), Direction::Right), ((width - 1, y), Direction::Left)]))
+            .collect();
+
+        starts.par_iter()
+            .map(|&(pos, dir)| energized_count(contraption, pos, dir))
+            .max()
+            .unwrap_or(0)
+    }
+
+    // the tiles energized by the part 1 beam in yellow
+    fn render(contraption: &Self::Input) -> Vec<Frame> {
+        let mut visited: IndexMap<(usize, usize), Direction> = IndexMap::new();
+        get_energized_tiles(contraption, (0, 0), Direction::Right, &mut visited);
+
+        let frame = Frame::new("energized", contraption, |ch| if *ch == '.' { Color::BLACK } else { Color::GREY })
+            .overlay(visited.keys().copied().filter(|pos| contraption[*pos] == '.'), Color::YELLOW);
+
+        vec![frame]
+    }
+
+    // the part 1 beam spreading, in the order it energizes the tiles
+    fn animate(contraption: &Self::Input) -> Vec<Frame> {
+        let mut visited: IndexMap<(usize, usize), Direction> = IndexMap::new();
+        get_energized_tiles(contraption, (0, 0), Direction::Right, &mut visited);
+
+        let tiles: Vec<(usize, usize)> = visited.keys().copied().filter(|pos| contraption[*pos] == '.').collect();
+
+        animate::checkpoints(tiles.len()).into_iter()
+            .map(|n| {
+                Frame::new("beam", contraption, |ch| if *ch == '.' { Color::BLACK } else { Color::GREY })
+                    .overlay(tiles[..n].iter().copied(), Color::YELLOW)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,124 @@
+use crate::animate;
+use crate::input::ParseResult;
+use crate::render::{Color, Frame};
+use crate::solution::Solution;
+use crate::utils::direction::Direction;
+use crate::utils::grid::Grid;
+use crate::utils::search;
+
+use indexmap::IndexSet;
+
+// (minimum, maximum) steps in a straight line
+const CRUCIBLE: (usize, usize) = (0, 3);
+const ULTRA_CRUCIBLE: (usize, usize) = (4, 10);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct Crucible {
+    pos: (usize, usize),
+    dir: Direction,
+    dir_steps: u32
+}
+
+fn successors(curr: Crucible, (min_steps, max_steps): (usize, usize), map: &Grid<u32>) -> Vec<Crucible> {
+    
+    if curr.dir_steps < min_steps as u32 { // continue to move forward
+        return map.step(curr.pos, curr.dir)
+            .map(|pos| Crucible { pos, dir: curr.dir, dir_steps: curr.dir_steps + 1 })
+            .into_iter()
+            .collect();
+    }
+    
+    let mut succs = vec![];
+
+    // keep going, or turn when there is room for the minimum steps in the new direction
+    for dir in [curr.dir, curr.dir.turn_left(), curr.dir.turn_right()] {
+        let Some(pos) = map.step(curr.pos, dir) else { continue };
+
+        if dir == curr.dir {
+            if curr.dir_steps < max_steps as u32 {
+                succs.push(Crucible { pos, dir, dir_steps: curr.dir_steps + 1 });
+            }
+        } else if map.steps(curr.pos, dir, min_steps).is_some() {
+            succs.push(Crucible { pos, dir, dir_steps: 1 }); // switch dir
+        }
+    }
+
+    succs
+}
+
+// `settled` is called with every state once its cheapest heat loss is known, in that order
+fn best_path(map: &Grid<u32>, step_rules: (usize, usize), mut settled: impl FnMut(&Crucible)) -> Option<search::Path<Crucible, u32>> {
+    let goal = (map.width() - 1, map.height() - 1);
+    let starts = [Direction::Right, Direction::Down].map(|dir| Crucible { pos: (0, 0), dir, dir_steps: 0 });
+
+    search::dijkstra(
+        starts,
+        |curr| successors(*curr, step_rules, map).into_iter().map(|next| (next, map[next.pos])),
+        |curr| {
+            settled(curr);
+            curr.pos == goal
+        })
+}
+
+// the heat loss of the best path, a narrow map may leave no way to the factory
+fn heat_loss(map: &Grid<u32>, step_rules: (usize, usize), crucible: &str) -> String {
+    match best_path(map, step_rules, |_| {}) {
+        Some(path) => path.cost.to_string(),
+        None => format!("the {} can't reach the factory", crucible)
+    }
+}
+
+pub struct ClumsyCrucible;
+
+impl Solution for ClumsyCrucible {
+    type Input = Grid<u32>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    const PART1: &'static str = "Heat loss in optimal path";
+    const PART2: &'static str = "Heat loss in optimal path";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let grid = Grid::parse(input, |ch| ch.is_ascii_digit())?;
+
+        Ok(grid.map(|ch| ch.to_digit(10).unwrap()))
+    }
+
+    fn part1(map: &Self::Input) -> String {
+        heat_loss(map, CRUCIBLE, "crucible")
+    }
+
+    fn part2(map: &Self::Input) -> String {
+        heat_loss(map, ULTRA_CRUCIBLE, "ultra crucible")
+    }
+
+    // the heat loss from dark (1) to bright (9), with the path of each crucible in yellow
+    fn render(map: &Self::Input) -> Vec<Frame> {
+        [("path", CRUCIBLE), ("ultra_path", ULTRA_CRUCIBLE)].into_iter()
+            .filter_map(|(name, step_rules)| {
+                let path = best_path(map, step_rules, |_| {})?;
+                let frame = Frame::new(name, map, |heat| Color::DARK_GREY.blend(Color::WHITE, (*heat as f64 - 1.0) / 8.0))
+                    .overlay(path.states.iter().map(|c| c.pos), Color::YELLOW);
+                Some(frame)
+            })
+            .collect()
+    }
+
+    // the part 1 search frontier: the tiles in the order the search settles them, then the path
+    fn animate(map: &Self::Input) -> Vec<Frame> {
+        let heat_map = |name: &str| Frame::new(name, map, |heat| Color::DARK_GREY.blend(Color::WHITE, (*heat as f64 - 1.0) / 8.0));
+
+        let mut tiles = IndexSet::new();
+        let Some(path) = best_path(map, CRUCIBLE, |curr| { tiles.insert(curr.pos); }) else {
+            return vec![];
+        };
+        let tiles: Vec<(usize, usize)> = tiles.into_iter().collect();
+
+        let mut frames: Vec<Frame> = animate::checkpoints(tiles.len()).into_iter()
+            .map(|n| heat_map("search").overlay(tiles[..n].iter().copied(), Color::BLUE))
+            .collect();
+        frames.push(heat_map("path").overlay(path.states.iter().map(|c| c.pos), Color::YELLOW));
+
+        frames
+    }
+}
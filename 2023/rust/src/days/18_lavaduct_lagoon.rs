@@ -0,0 +1,244 @@
+use crate::input::{self, ParseError, ParseResult};
+use crate::render::{Color, Frame};
+use crate::solution::Solution;
+use crate::utils::compressed_grid::CompressedGrid;
+use crate::utils::direction::Direction;
+use crate::utils::geometry::{Point, Polygon};
+use crate::utils::grid::Grid;
+use crate::utils::search;
+
+use std::collections::{HashMap, HashSet};
+
+fn parse_dig_direction(text: &str) -> ParseResult<DigDirection> {
+    let [dir_part, steps, color_part] = input::fields(text, text, "fields, the direction, steps and color")?;
+    let dir = Direction::from_letter(dir_part)
+        .ok_or_else(|| ParseError::at(text, dir_part, format!("invalid direction '{}'", dir_part)))?;
+    let steps = input::value(text, steps, "steps")?;
+
+    // part 2 reads the real instruction from the color: 5 hex digits of steps and the direction (0-3)
+    let color = Some(input::enclosed(text, color_part, '(', ')')?)
+        .filter(|c| c.len() == 7 && c.starts_with("#") && c[1..].chars().all(|ch| ch.is_ascii_hexdigit()))
+        .ok_or_else(|| ParseError::at(text, color_part, format!("invalid color '{}'", color_part)))?;
+    if !matches!(&color[6..], "0" | "1" | "2" | "3") {
+        return Err(ParseError::at(text, &color[6..], format!("invalid direction '{}' in color, expected 0 to 3", &color[6..])));
+    }
+
+    Ok(DigDirection {dir, steps, color: color.to_string()})
+}
+
+pub struct DigDirection {
+    dir: Direction,
+    steps: u32,
+    color: String
+}
+
+/**
+ * The trench as '#', and '|' where it also goes up from the tile to the one above, as parallel trenches
+ * can run right next to each other and being next to a trench does not mean being linked to it.
+ */
+fn build_dig_map(dig_plan: &[DigDirection]) -> Grid<char> {
+
+    // first build a hashmap of all the edge points    
+    let mut edges = HashMap::new();
+    let mut up_links = HashSet::new();
+    let mut row = 0;
+    let mut col = 0;
+    let mut max_w = 0;
+    let mut max_h = 0;
+    let mut min_w = 0;
+    let mut min_h = 0;
+    for dd in dig_plan {
+        let (dx, dy) = dd.dir.offset();
+        for _ in 0..dd.steps {
+            edges.insert((col, row), &dd.color);
+            match dd.dir {
+                Direction::Up => { up_links.insert((col, row)); },
+                Direction::Down => { up_links.insert((col, row + 1)); },
+                _ => {}
+            }
+            col += dx;
+            if col + 1 > max_w {
+                max_w = col + 1;
+            }
+            if col < min_w {
+                min_w = col;
+            }
+            row += dy;
+            if row + 1 > max_h {
+                max_h = row + 1;
+            }
+            if row < min_h {
+                min_h = row;
+            }
+        }
+    }
+    
+    // build a grid of all the points
+    let width = (max_w - min_w) as usize;
+    let height = (max_h - min_h) as usize;
+    
+    Grid::from_fn(width, height, |(c, r)| {
+        let pos = (c as i64 + min_w, r as i64 + min_h);
+        if up_links.contains(&pos) { '|' } else if edges.contains_key(&pos) { '#' } else { '.' }
+    })
+}
+
+// Where the plan ends up, which must be where it started
+fn plan_end(dig_plan: &[DigDirection]) -> Point<i64> {
+    dig_plan.iter().fold(Point::new(0, 0), |end, dd| {
+        let (dx, dy) = dd.dir.offset();
+        end + Point::new(dx * dd.steps as i64, dy * dd.steps as i64)
+    })
+}
+
+// The trench corners, the plan goes around and comes back to the start
+fn trench_polygon(dig_plan: &[DigDirection]) -> Polygon<i64> {
+    let mut corner = Point::new(0, 0);
+    let mut corners = vec![];
+
+    for dd in dig_plan {
+        corners.push(corner);
+        let (dx, dy) = dd.dir.offset();
+        corner = corner + Point::new(dx * dd.steps as i64, dy * dd.steps as i64);
+    }
+
+    Polygon::new(corners)
+}
+
+// Every point the trench goes through and every point it encloses, from the shoelace area and Pick's theorem
+fn lagoon_volume(dig_plan: &[DigDirection]) -> u128 {
+    let trench = trench_polygon(dig_plan);
+
+    (trench.interior_points() + trench.boundary_points()) as u128
+}
+
+/**
+ * The trench and the tiles it encloses, by flooding the ground from outside on a compressed grid, cut around every
+ * trench and one tile of ground beyond, so it takes the same time whatever the distances.
+ */
+fn flood_fill_volume(dig_plan: &[DigDirection]) -> u64 {
+    let trench = trench_polygon(dig_plan);
+    let corners = trench.vertices();
+    let cuts = |coord: fn(&Point<i64>) -> i64| {
+        let (min, max) = (corners.iter().map(coord).min().unwrap(), corners.iter().map(coord).max().unwrap());
+        corners.iter().flat_map(move |c| [coord(c), coord(c) + 1]).chain([min - 1, max + 2])
+    };
+
+    let mut ground = CompressedGrid::new(cuts(|c| c.x), cuts(|c| c.y), false);
+    for edge in trench.edges() {
+        let (a, b) = (edge.start, edge.end);
+        ground.fill(a.x.min(b.x)..a.x.max(b.x) + 1, a.y.min(b.y)..a.y.max(b.y) + 1, true);
+    }
+
+    let cells = ground.cells();
+    let outside = search::reachable((0, 0), |cell| cells.neighbours4(*cell).filter(|c| !cells[*c]), None);
+
+    let total: u64 = cells.positions().map(|cell| ground.area(cell)).sum();
+    total - outside.into_iter().map(|(cell, _)| ground.area(cell)).sum::<u64>()
+}
+
+// the real plan hidden in the colors
+fn decode_colors(dig_plan: &[DigDirection]) -> Vec<DigDirection> {
+    dig_plan.iter()
+        .map(|dd| {
+            let raw = dd.color.strip_prefix("#").unwrap();
+            let steps: u32 = u32::from_str_radix(&raw[0..5], 16).unwrap();
+
+            let dir = match &raw[5..6] {
+                "0" => Direction::Right,
+                "1" => Direction::Down,
+                "2" => Direction::Left,
+                "3" => Direction::Up,
+                _ => unreachable!()
+            };
+
+            DigDirection {dir, steps, color: String::new()}
+        })
+        .collect()
+}
+
+pub struct LavaductLagoon;
+
+impl Solution for LavaductLagoon {
+    type Input = Vec<DigDirection>;
+    type Answer1 = u64;
+    type Answer2 = u128;
+
+    const PART1: &'static str = "Cubic meters of lava";
+    const PART2: &'static str = "Cubic meters of lava after bug is fixed";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let dig_plan = input::parse_lines(input, parse_dig_direction)?;
+        if dig_plan.is_empty() {
+            return Err(ParseError::new(1, 1, "empty dig plan"));
+        }
+
+        // an open plan would be closed by a diagonal trench
+        for (plan, what) in [(&dig_plan, "dig plan"), (&decode_colors(&dig_plan), "dig plan in the colors")] {
+            let end = plan_end(plan);
+            if end != Point::new(0, 0) {
+                return Err(ParseError::new(dig_plan.len(), 1, format!("the {} ends at ({}, {}), not back at its start", what, end.x, end.y)));
+            }
+        }
+
+        Ok(dig_plan)
+    }
+
+    fn part1(dig_plan: &Self::Input) -> u64 {
+        flood_fill_volume(dig_plan)
+    }
+
+    fn part2(dig_plan: &Self::Input) -> u128 {
+        // solution based on shoelace and picks theorem (shamely stolen from https://www.youtube.com/watch?v=bGWK76_e-LM)
+        lagoon_volume(&decode_colors(dig_plan))
+    }
+
+    // the part 1 trench
+    fn render(dig_plan: &Self::Input) -> Vec<Frame> {
+        let map = build_dig_map(dig_plan);
+
+        vec![Frame::new("trench", &map, |ch| if *ch == '.' { Color::BLACK } else { Color::BROWN })]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{agree, Shrink};
+
+    // naive solution - check the area of the map line by line, the ray from the left edge is inside
+    // after crossing an odd number of trenches going up
+    fn dig_area(grid: &Grid<char>) -> u64 {
+        let mut sum = 0;
+        for r in 0..grid.height() {
+            let mut inside = false;
+            for c in 0..grid.width() {
+                match grid[(c, r)] {
+                    '|' => {
+                        sum += 1;
+                        inside = !inside;
+                    },
+                    '#' => sum += 1,
+                    _ if inside => sum += 1,
+                    _ => {}
+                }
+            }
+        }
+
+        sum
+    }
+
+    #[test]
+    fn dug_map_agrees_with_shoelace() {
+        agree::<LavaductLagoon, u128>(18, &[1, 5, 20, 60], 20, Shrink::Size,
+            |dig_plan| dig_area(&build_dig_map(dig_plan)) as u128,
+            |dig_plan| lagoon_volume(dig_plan));
+    }
+
+    #[test]
+    fn flood_fill_agrees_with_shoelace_on_decoded_plans() {
+        agree::<LavaductLagoon, u128>(18, &[1, 5, 20, 60], 20, Shrink::Size,
+            |dig_plan| flood_fill_volume(&decode_colors(dig_plan)) as u128,
+            |dig_plan| lagoon_volume(&decode_colors(dig_plan)));
+    }
+}
@@ -0,0 +1,214 @@
+use crate::input::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::utils::range_set::RangeSet;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Hash, Clone, Eq, PartialEq)]
+pub struct Rule {
+    rating: char,
+    op: char,
+    val: u32,
+    res: String
+}
+
+#[derive(Debug, Hash, Copy, Clone, Eq, PartialEq)]
+pub struct Part {
+    x: u32, 
+    m: u32, 
+    a: u32,
+    s: u32
+}
+
+fn parse_wf(text: &str) -> ParseResult<(String, Vec<Rule>)> {
+    let (name, rules) = input::record(text, text)?;
+    if name.is_empty() {
+        return Err(ParseError::new(0, 1, "missing workflow name"));
+    }
+    let rules = rules.split(',').map(|r| parse_rule(text, r.trim())).collect::<ParseResult<_>>()?;
+
+    Ok((name.to_string(), rules))
+}
+
+// `rule` is a slice of the workflow line `text`
+fn parse_rule(text: &str, rule: &str) -> ParseResult<Rule> {
+    match rule.split_once(':') {
+        Some((condition, res)) => {
+            let mut chars = condition.chars();
+            let rating = chars.next().filter(|r| "xmas".contains(*r))
+                .ok_or_else(|| ParseError::at(text, condition, format!("invalid rating in rule '{}'", rule)))?;
+            let op = chars.next().filter(|o| "<>".contains(*o))
+                .ok_or_else(|| ParseError::at(text, condition, format!("invalid comparison in rule '{}'", rule)))?;
+            let val = input::value(text, chars.as_str(), "rule value")?;
+
+            Ok(Rule { rating, op, val, res: res.trim().to_string() })
+        },
+        None => Ok(Rule { rating: '.', op: '.', val: 0, res: rule.to_string() })
+    }
+}
+
+// Every rule of a parsed workflow line sends parts to "A", "R" or one of the workflows, and the last one always does
+fn check_targets(text: &str, workflows: &HashMap<String, Vec<Rule>>) -> ParseResult<()> {
+    let (_, rules) = input::record(text, text)?;
+    let rules: Vec<&str> = rules.split(',').map(str::trim).collect();
+
+    for rule in &rules {
+        let target = rule.split_once(':').map_or(*rule, |(_, res)| res.trim());
+        if target != "A" && target != "R" && !workflows.contains_key(target) {
+            return Err(ParseError::at(text, target, format!("no workflow named '{}'", target)));
+        }
+    }
+    if let Some(last) = rules.last().filter(|r| r.contains(':')) {
+        return Err(ParseError::at(text, last, "the last rule of a workflow must have no condition"));
+    }
+
+    Ok(())
+}
+
+fn parse_part(text: &str) -> ParseResult<Part> {
+    let (name, ratings) = input::record(text, text)?;
+    if !name.is_empty() {
+        return Err(ParseError::at(text, name, "expected '{x=..,m=..,a=..,s=..}'"));
+    }
+    let mut ratings = ratings.split(',');
+    let mut ps = [0, 0, 0, 0];
+    for (i, name) in ["x", "m", "a", "s"].iter().enumerate() {
+        let rat = input::next(text, &mut ratings, &format!("'{}' rating", name))?.trim();
+        let val = input::after(text, rat, name).and_then(|r| input::after(text, r, "="))
+            .map_err(|_| ParseError::at(text, rat, format!("expected '{}=..'", name)))?;
+        ps[i] = input::value(text, val, "rating")?;
+    }
+
+    Ok(Part { x: ps[0], m: ps[1], a: ps[2], s: ps[3] })
+}
+
+fn is_accepted_part(part: Part, workflows: &HashMap<String, Vec<Rule>>) -> bool {
+    let mut cr = "in";
+
+    while cr != "A" && cr != "R" {
+        for r in &workflows[cr] {
+            let m = match r.rating {
+                'x' => match r.op {
+                    '>' if part.x > r.val => true,
+                    '<' if part.x < r.val => true,
+                    _ => false
+                },
+                'm' => match r.op {
+                    '>' if part.m > r.val => true,
+                    '<' if part.m < r.val => true,
+                    _ => false
+                },
+                'a' => match r.op {
+                    '>' if part.a > r.val => true,
+                    '<' if part.a < r.val => true,
+                    _ => false
+                }
+                's' => match r.op {
+                    '>' if part.s > r.val => true,
+                    '<' if part.s < r.val => true,
+                    _ => false
+                }
+                '.' => true,
+                _ => unreachable!()
+            };
+
+            if m {
+                cr = &r.res;
+                break;
+            }
+        }
+    }
+
+    cr == "A"
+} 
+
+// (the ratings that match the rule, the ones that do not)
+fn match_range(r: &Rule, ratings: &RangeSet<u32>) -> (RangeSet<u32>, RangeSet<u32>) {
+    match r.op {
+        '>' => {
+            let (rr, nr) = ratings.split_at(r.val + 1);
+            (nr, rr)
+        },
+        '<' => ratings.split_at(r.val),
+        _ => unreachable!()
+    }
+}
+
+fn find_accepted_ranges(wf: &str, mut ranges: Vec<RangeSet<u32>>, workflows: &HashMap<String, Vec<Rule>>) -> u64 {
+    if wf == "A" {
+        return ranges.iter().map(|r| r.count()).product();
+    }
+
+    if wf == "R" {
+        return 0;
+    }
+
+    let mut sum = 0;
+
+    for r in &workflows[wf] {
+        let ri_op = match r.rating {
+            'x' => Some(0),
+            'm' => Some(1),
+            'a' => Some(2),
+            's' => Some(3),
+            _ => None
+        };
+
+        if let Some(ri) = ri_op  {
+            let (nr, rr) = match_range(r, &ranges[ri]);
+            ranges[ri] = rr;
+            let mut n_ranges = ranges.clone();
+            n_ranges[ri] = nr;
+            sum += find_accepted_ranges(&r.res, n_ranges, workflows);
+        } else {
+            sum += find_accepted_ranges(&r.res, ranges.clone(), workflows);
+        }
+    }
+
+    sum
+}
+
+pub struct System {
+    workflows: HashMap<String, Vec<Rule>>,
+    parts: Vec<Part>
+}
+
+pub struct Aplenty;
+
+impl Solution for Aplenty {
+    type Input = System;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    const PART1: &'static str = "Sum of accepted part ratings";
+    const PART2: &'static str = "Combinations of accepted rating ranges";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let blocks = input::blocks(input);
+        let [(wf_lines, wf_block), (part_lines, part_block)] = input::exactly(input, input, blocks, "blocks, the workflows and the parts")
+            .map_err(|e| e.on_line(1))?;
+
+        let workflows: HashMap<String, Vec<Rule>> = input::parse_lines(wf_block, parse_wf)
+            .map_err(|e| e.shifted(wf_lines))?
+            .into_iter()
+            .collect();
+        let parts = input::parse_lines(part_block, parse_part).map_err(|e| e.shifted(part_lines))?;
+
+        // every part starts at the "in" workflow
+        if !workflows.contains_key("in") {
+            return Err(ParseError::new(1, 1, "missing the 'in' workflow"));
+        }
+        input::parse_lines(wf_block, |text| check_targets(text, &workflows)).map_err(|e| e.shifted(wf_lines))?;
+
+        Ok(System { workflows, parts })
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        input.parts.iter().filter(|p| is_accepted_part(**p, &input.workflows)).map(|p| p.x + p.m + p.a + p.s).sum()
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        find_accepted_ranges("in", vec![RangeSet::from_range(1..4001); 4], &input.workflows)
+    }
+}
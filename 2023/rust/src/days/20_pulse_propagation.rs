@@ -1,12 +1,10 @@
-mod utils;
+use crate::input::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::utils::math;
 
 use std::collections::{ HashMap, VecDeque };
 use std::hash::Hash;
 
-const INPUT_FILE: &str = "./input/20_input.txt";
-// const INPUT_FILE: &str = "./input/20_input_test.txt";
-// const INPUT_FILE: &str = "./input/20_input_test2.txt";
-
 #[derive(Debug, Hash, Copy, Clone, Eq, PartialEq)]
 enum Type {
     FlipFlop,
@@ -15,15 +13,17 @@ enum Type {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct Module {
+pub struct Module {
     name: String,
     m_type: Type,
     targets: Vec<String>
 }
 
-fn parse_module(text: &str) -> Module {
-    let mut s1 = text.split("->");
-    let mut name = s1.next().unwrap().trim();
+fn parse_module(text: &str) -> ParseResult<Module> {
+    let (mut name, targets) = input::split_once(text, text, "->", "the module targets")?;
+    if name.is_empty() {
+        return Err(ParseError::new(0, 1, "missing module name"));
+    }
 
     let m_type = match &name[0..1] {
         "%" => Type::FlipFlop,
@@ -35,10 +35,9 @@ fn parse_module(text: &str) -> Module {
         name = &name[1..];
     }
 
-    let targets = s1.next().unwrap().trim();
-    let targets: Vec<String> = targets.split(",").map(|ts| ts.trim().to_string()).collect();
+    let targets = input::separated(text, targets, ',', "module target")?;
 
-    Module {name: name.trim().to_string(), m_type, targets }
+    Ok(Module {name: name.trim().to_string(), m_type, targets })
 }
 
 fn pulse_module(fm: &Module, tm: &Module, p: bool, states: &mut HashMap<String, bool>) -> bool {
@@ -75,12 +74,12 @@ fn broadcast(modules: &HashMap<String, Module>, states: &mut HashMap<String, boo
         match m.m_type {
             Type::Broadcast => {
                 for tmn in &m.targets {
-                    let tm = &modules[tmn];
-                    
-                    // pulse target
-                    let res = pulse_module(m, tm, false, states);
-                    if res {
-                        queue.push_back(tm);
+                    if let Some(tm) = modules.get(tmn) {
+                        // pulse target
+                        let res = pulse_module(m, tm, false, states);
+                        if res {
+                            queue.push_back(tm);
+                        }
                     }
 
                     low += 1;
@@ -100,7 +99,7 @@ fn broadcast(modules: &HashMap<String, Module>, states: &mut HashMap<String, boo
                         }
                     }
 
-                    if curr == false {
+                    if !curr {
                         low += 1;
                     } else {
                         high += 1;
@@ -115,16 +114,15 @@ fn broadcast(modules: &HashMap<String, Module>, states: &mut HashMap<String, boo
                     k.starts_with(&key)
                 });
 
-                let all_high = conj_inputs.clone().all(|(_, p)| *p == true);
+                let all_high = conj_inputs.clone().all(|(_, p)| *p);
 
                 if let Some(rx_conj) = rx_conj_op {
                     if rx_conj == m.name { // we are in the rx conjunction module (part 2)
                         for (mi, p) in conj_inputs {
-                            if *p == true {
-                                if !seen.contains(mi) {
+                            if *p
+                                && !seen.contains(mi) {
                                     seen.push(mi.clone());
                                 }
-                            }
                         }
                     }
                 }
@@ -151,7 +149,7 @@ fn broadcast(modules: &HashMap<String, Module>, states: &mut HashMap<String, boo
     (high, low, seen)
 }
 
-fn count_pulses(modules: &mut HashMap<String, Module>) -> u32 {
+fn count_pulses(modules: &HashMap<String, Module>) -> u32 {
     let mut states: HashMap<String, bool> = HashMap::new();
     
     init_modules_state(modules, &mut states);
@@ -169,9 +167,7 @@ fn count_pulses(modules: &mut HashMap<String, Module>) -> u32 {
     low * high
 }
 
-fn count_rx_active_low(modules: &mut HashMap<String, Module>) -> usize {
-    let rx_conj = find_conjunction_module_for_rx(modules);
-
+fn count_rx_active_low(modules: &HashMap<String, Module>, rx_conj: &Module) -> u64 {
     let mut rx_input_cycles: HashMap<String, u32> = HashMap::new();
     
     let mut states: HashMap<String, bool> = HashMap::new();
@@ -198,20 +194,13 @@ fn count_rx_active_low(modules: &mut HashMap<String, Module>) -> usize {
         }
     }
 
-    let nums: Vec<usize> = rx_input_cycles.values().map(|v|*v as usize).collect();
+    let nums = rx_input_cycles.values().map(|v| *v as u64);
     
-    utils::lcm(&nums)
-}
-
-fn find_conjunction_module_for_rx(modules: &HashMap<String, Module>) -> &Module {
-    let cm = modules.values().find(|m| m.targets.contains(&"rx".to_string())).unwrap();
-    assert!(cm.m_type == Type::Conjunction);
-
-    cm
+    math::lcm_all(nums).expect("The button presses for rx overflow")
 }
 
 fn init_modules_state(modules: &HashMap<String, Module>, states: &mut HashMap<String, bool>) {
-    for (_, m) in modules {
+    for m in modules.values() {
         match m.m_type {
             Type::FlipFlop => {
                 let mut key = m.name.clone();
@@ -219,7 +208,7 @@ fn init_modules_state(modules: &HashMap<String, Module>, states: &mut HashMap<St
                 states.insert(key, false);
             },
             Type::Conjunction => {
-                for (_, mm) in modules {
+                for mm in modules.values() {
                     if mm.targets.contains(&m.name) {
                         let mut key = m.name.clone();
                         key.push('#');
@@ -233,31 +222,54 @@ fn init_modules_state(modules: &HashMap<String, Module>, states: &mut HashMap<St
     }
 }
 
-fn _print_modules_state(states: &mut HashMap<String, bool>) {
-    println!("===========================");
-    for (m, s) in states {
-        println!("{m} = {s}");
-    }
+pub struct Network {
+    modules: HashMap<String, Module>,
+    // the conjunction module sending pulses to rx, the examples have none
+    rx_feeder: Option<String>
 }
 
-fn main() {
-    if let Ok(lines) = utils::read_lines(INPUT_FILE) {
+pub struct PulsePropagation;
+
+impl Solution for PulsePropagation {
+    type Input = Network;
+    type Answer1 = u32;
+    type Answer2 = String;
+
+    const PART1: &'static str = "Multiplication of low pulses with high pulses";
+    const PART2: &'static str = "Fewest number of button presses required for rx";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
         let mut modules: HashMap<String, Module> = HashMap::new();
+        let mut rx_feeder = None;
 
-        for line in lines {
-            if let Ok(text) = line {
-                let module = parse_module(&text);
-                modules.insert(module.name.clone(), module);
+        for (i, module) in input::parse_lines(input, parse_module)?.into_iter().enumerate() {
+            if module.targets.iter().any(|t| t == "rx") {
+                if module.m_type != Type::Conjunction {
+                    return Err(ParseError::new(i + 1, 1, "the module sending pulses to rx must be a conjunction"));
+                }
+                if rx_feeder.replace(module.name.clone()).is_some() {
+                    return Err(ParseError::new(i + 1, 1, "a single module can send pulses to rx"));
+                }
             }
+            modules.insert(module.name.clone(), module);
         }
-        
-        let sum1 = count_pulses(&mut modules);
-        println!("[Part 1] Multiplication of low pulses with high pulses : {}", sum1);
 
-        let sum2 = count_rx_active_low(&mut modules);
-        println!("[Part 2] Fewest number of button presses required for rx : {}", sum2);
-    } else {
-        eprintln!("Could not load module communication from {}", INPUT_FILE);
+        // the button pulses the broadcaster, this also rules out an empty input
+        if !modules.contains_key("broadcaster") {
+            return Err(ParseError::new(1, 1, "missing the 'broadcaster' module"));
+        }
+
+        Ok(Network { modules, rx_feeder })
+    }
+
+    fn part1(network: &Self::Input) -> u32 {
+        count_pulses(&network.modules)
     }
 
+    fn part2(network: &Self::Input) -> String {
+        match &network.rx_feeder {
+            Some(feeder) => count_rx_active_low(&network.modules, &network.modules[feeder]).to_string(),
+            None => "no module sends pulses to rx".to_string()
+        }
+    }
 }
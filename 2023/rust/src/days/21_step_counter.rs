@@ -0,0 +1,249 @@
+use crate::input::{ParseError, ParseResult};
+use crate::render::{Color, Frame};
+use crate::solution::Solution;
+use crate::utils::grid::Grid;
+use crate::utils::math;
+use crate::utils::search;
+use crate::utils::tiled_grid::TiledGrid;
+
+use std::collections::HashSet;
+
+const STEPS_1: usize = 64;
+const EXAMPLE_STEPS_1: usize = 6;
+const STEPS_2: usize = 26501365;
+
+fn neighbors(pos: (usize, usize), grid: &Grid<char>) -> impl Iterator<Item = (usize, usize)> + '_ {
+    grid.neighbours4(pos).filter(|p| grid[*p] != '#')
+}
+
+// the plots the walk can end on: it can go back and forth, so any plot reached with an even number of steps left
+fn count_plots(start: (usize, usize), steps: usize, grid: &Grid<char>) ->  HashSet<(usize, usize)> {
+    search::reachable(start, |pos| neighbors(*pos, grid), Some(steps)).into_iter()
+        .filter(|(_, distance)| (steps - distance).is_multiple_of(2))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+/**
+ * The plots reached in the garden repeated forever, walking the tiled garden. Past the rocks around the start, the
+ * count after `steps % period + k * period` steps grows as a quadratic of k, so the walk goes a few more gardens
+ * further each time until the last second differences agree, and the rest is extrapolated from there.
+ */
+fn infinite_plots((sx, sy): (usize, usize), steps: usize, grid: &Grid<char>) -> usize {
+    let tiled = TiledGrid::new(grid);
+    let period = math::lcm(grid.width(), grid.height()).expect("the garden is too large");
+    let remainder = steps % period;
+
+    let mut gardens = 4;
+    loop {
+        let max_steps = steps.min(remainder + gardens * period);
+        let distances = search::reachable((sx as i64, sy as i64), |pos| tiled.neighbours4(*pos).filter(|p| tiled[*p] != '#'), Some(max_steps));
+        let plots = |steps: usize| distances.iter().filter(|(_, d)| *d <= steps && (steps - d).is_multiple_of(2)).count() as i64;
+
+        if max_steps == steps {
+            return plots(steps) as usize;
+        }
+
+        let counts: Vec<i64> = (0..=gardens).map(|k| plots(remainder + k * period)).collect();
+        let second: Vec<i64> = counts.windows(3).map(|w| w[2] - 2 * w[1] + w[0]).collect();
+
+        if second[second.len() - 3..].iter().all(|d| *d == second[second.len() - 1]) {
+            // Newton's forward differences from the last garden but one
+            let base = gardens - 1;
+            let m = ((steps - remainder) / period - base) as i64;
+            let first = counts[base + 1] - counts[base];
+            return (counts[base] + m * first + m * (m - 1) / 2 * second[second.len() - 1]) as usize;
+        }
+
+        assert!(gardens < 64, "the walk does not settle into a steady growth");
+        gardens *= 2;
+    }
+}
+
+fn find_start(grid: &Grid<char>) -> (usize, usize) {
+    grid.find(&'S').unwrap()
+}
+
+pub struct Garden {
+    grid: Grid<char>,
+    // the steps of part 1
+    steps: usize
+}
+
+pub struct StepCounter;
+
+impl Solution for StepCounter {
+    type Input = Garden;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PART1: &'static str = "Number of garden plots the Elf can reach";
+    const PART2: &'static str = "Number of garden plots the Elf can reach in exactly 26501365 steps";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let grid = Grid::parse(input, |ch| ".#S".contains(ch))?;
+
+        if grid.find_all(&'S').count() != 1 {
+            return Err(ParseError::new(1, 1, "the garden must have exactly one starting point 'S'"));
+        }
+
+        Ok(Garden { grid, steps: STEPS_1 })
+    }
+
+    // the example garden is smaller than the real one, and asks for a shorter walk
+    fn example(garden: Self::Input) -> Self::Input {
+        Garden { steps: EXAMPLE_STEPS_1, ..garden }
+    }
+
+    fn part1(garden: &Self::Input) -> usize {
+        count_plots(find_start(&garden.grid), garden.steps, &garden.grid).len()
+    }
+
+    fn part2(garden: &Self::Input) -> usize {
+        infinite_plots(find_start(&garden.grid), STEPS_2, &garden.grid)
+    }
+
+    // the plots of part 1 in green
+    fn render(garden: &Self::Input) -> Vec<Frame> {
+        let grid = &garden.grid;
+        let start = find_start(grid);
+        let plots = count_plots(start, garden.steps, grid);
+
+        let frame = Frame::new("plots", grid, |ch| if *ch == '#' { Color::GREY } else { Color::BLACK })
+            .overlay(plots, Color::GREEN)
+            .overlay([start], Color::RED);
+
+        vec![frame]
+    }
+
+    // the part 1 walk step by step: the plots it can end on in green, the wavefront in yellow
+    fn animate(garden: &Self::Input) -> Vec<Frame> {
+        let grid = &garden.grid;
+        let start = find_start(grid);
+        let distances = search::reachable(start, |pos| neighbors(*pos, grid), Some(garden.steps));
+
+        (0..=garden.steps)
+            .map(|step| {
+                let plots = distances.iter().filter(|(_, d)| *d <= step && (step - d).is_multiple_of(2)).map(|(pos, _)| *pos);
+                let front = distances.iter().filter(|(_, d)| *d == step).map(|(pos, _)| *pos);
+
+                Frame::new(&format!("step_{}", step), grid, |ch| if *ch == '#' { Color::GREY } else { Color::BLACK })
+                    .overlay(plots, Color::GREEN)
+                    .overlay(front, Color::YELLOW)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{agree, Shrink};
+
+    use std::collections::VecDeque;
+
+    /* Shamefully borrowed from HyperNeutrino (https://www.youtube.com/watch?v=9UOMZSL0JTg),
+       only for a square garden with the start in the middle and free paths to the edges */
+    fn infinite_plots2((sx, sy): (usize, usize), steps: usize, grid: &Grid<char>) -> usize {
+        let size = grid.width();
+        let grid_width = steps / size - 1;
+
+        let odd = (grid_width / 2 * 2 + 1).pow(2);
+        let even = (grid_width.div_ceil(2) * 2).pow(2);
+
+        let odd_points = count_plots((sx, sy), size * 2 + 1, grid).len();
+        let even_points = count_plots((sx, sy), size * 2, grid).len();
+
+        let corner_t = count_plots((sx, size - 1), size - 1, grid).len();
+        let corner_r = count_plots((0, sy), size - 1, grid).len();
+        let corner_b = count_plots((sx, 0), size - 1, grid).len();
+        let corner_l = count_plots((size - 1, sy), size - 1, grid).len();
+
+        let small_tr = count_plots((0, size - 1), size / 2 - 1, grid).len();
+        let small_tl = count_plots((size - 1, size - 1), size / 2 - 1, grid).len();
+        let small_br = count_plots((0, 0), size / 2 - 1, grid).len();
+        let small_bl = count_plots((size - 1, 0), size / 2 - 1, grid).len();
+
+        let large_tr = count_plots((0, size - 1), size * 3 / 2 - 1, grid).len();
+        let large_tl = count_plots((size - 1, size - 1), size * 3 / 2 - 1, grid).len();
+        let large_br = count_plots((0, 0), size * 3 / 2 - 1, grid).len();
+        let large_bl = count_plots((size - 1, 0), size * 3 / 2 - 1, grid).len();
+
+        odd * odd_points +
+        even * even_points +
+        corner_t + corner_r + corner_b + corner_l +
+        (grid_width + 1) * (small_tr + small_tl + small_br + small_bl) +
+        grid_width * (large_tr + large_tl + large_br + large_bl)
+    }
+
+    /* slower solution */
+    fn count_plots2(start: (usize, usize), steps: usize, grid: &Grid<char>) ->  VecDeque<(usize, usize)> {
+        let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+        queue.push_back(start);
+
+        let mut step_count = 0;
+
+        while !queue.is_empty() && step_count < steps {
+            let mut seen = HashSet::new();
+
+            for _ in 0..queue.len() { // a single step processes all edges
+                // NOTE: although we are changing the queue as we go, the length for a step is fixed when starting the loop
+                let curr = queue.pop_front().unwrap();
+
+                let neighbors = neighbors(curr, grid);
+                for n in neighbors {
+                    if !seen.contains(&n) {
+                        seen.insert(n);
+                        queue.push_back(n);
+                    }
+                }
+            }
+
+            step_count += 1;
+        }
+
+        queue
+    }
+
+    #[test]
+    fn reachable_plots_agree_with_step_by_step_walk() {
+        agree::<StepCounter, usize>(21, &[5, 11, 30, 131], 10, Shrink::Size,
+            |garden| count_plots(find_start(&garden.grid), garden.steps, &garden.grid).len(),
+            |garden| count_plots2(find_start(&garden.grid), garden.steps, &garden.grid).len());
+    }
+
+    // the formula only works on gardens as wide as the puzzle's
+    #[test]
+    fn tiled_walk_agrees_with_formula() {
+        agree::<StepCounter, usize>(21, &[131], 2, Shrink::Size,
+            |garden| infinite_plots(find_start(&garden.grid), STEPS_2, &garden.grid),
+            |garden| infinite_plots2(find_start(&garden.grid), STEPS_2, &garden.grid));
+    }
+
+    // with more steps than the walk before extrapolating covers, on gardens of other sizes than the puzzle's
+    #[test]
+    fn tiled_walk_agrees_with_walking_every_step() {
+        let walk = |garden: &Garden, steps: usize| {
+            let tiled = TiledGrid::new(&garden.grid);
+            let (sx, sy) = find_start(&garden.grid);
+            search::reachable((sx as i64, sy as i64), |pos| tiled.neighbours4(*pos).filter(|p| tiled[*p] != '#'), Some(steps))
+                .into_iter()
+                .filter(|(_, d)| (steps - d).is_multiple_of(2))
+                .count()
+        };
+
+        agree::<StepCounter, usize>(21, &[5, 8, 13], 2, Shrink::Size,
+            |garden| infinite_plots(find_start(&garden.grid), 300, &garden.grid),
+            |garden| walk(garden, 300));
+    }
+
+    #[test]
+    fn tiled_walk_on_the_example() {
+        let grid = StepCounter::parse(&std::fs::read_to_string("./input/21_input_test.txt").unwrap()).unwrap().grid;
+
+        // from the puzzle, the example is not a garden the formula works on
+        for (steps, plots) in [(6, 16), (10, 50), (50, 1594), (100, 6536), (500, 167004), (1000, 668697), (5000, 16733044)] {
+            assert_eq!(infinite_plots(find_start(&grid), steps, &grid), plots, "{} steps", steps);
+        }
+    }
+}
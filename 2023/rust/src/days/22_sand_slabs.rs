@@ -0,0 +1,279 @@
+use crate::animate;
+use crate::input::{self, ParseResult};
+use crate::render::{Color, Frame};
+use crate::solution::Solution;
+use crate::utils::geometry::{Point, Segment};
+use crate::utils::grid::Grid;
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+const PALETTE: [Color; 6] = [Color::RED, Color::GREEN, Color::BLUE, Color::YELLOW, Color::WHITE, Color(160, 80, 200)];
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Brick {
+    id: u32,
+    begin: (u32, u32, u32),
+    end: (u32, u32, u32)
+}
+
+// implement ordering based on z
+impl PartialOrd for Brick {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Brick {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.begin.2.cmp(&other.begin.2)
+    }
+}
+
+#[derive(Debug, Hash, Clone, Eq, PartialEq)]
+struct SettledBrick {
+    id: u32,
+    begin: (u32, u32, u32),
+    end: (u32, u32, u32),
+    top: Vec<u32>,
+    bottom: Vec<u32>
+}
+
+// implement ordering based on z
+impl PartialOrd for SettledBrick {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for SettledBrick {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.end.2.cmp(&self.end.2)
+    }
+}
+
+impl SettledBrick {
+    fn new(b: &Brick) -> Self {
+        Self {
+            id: b.id,
+            begin: b.begin,
+            end: b.end,
+            top: vec![],
+            bottom: vec![]
+        }
+    }
+}
+
+// A brick seen from above, a segment of the (x, y) plane: two bricks are stacked when they overlap there
+fn footprint(begin: (u32, u32, u32), end: (u32, u32, u32)) -> Segment<i64> {
+    Segment::new(Point::new(begin.0 as i64, begin.1 as i64), Point::new(end.0 as i64, end.1 as i64))
+}
+
+fn settle_bricks(bricks: &[Brick]) -> Vec<SettledBrick> {
+    let mut settled_bricks: Vec<SettledBrick> = Vec::new();
+
+    for brick in bricks {
+        let mut z = 0;
+        let mut bottom_bricks = vec![];
+
+        for sb in settled_bricks.iter_mut() {
+            if z != 0 && sb.end.2 != z {
+                // exhausted all blocks at the intersect level
+                break;
+            }
+            let intersec = brick.begin.2 > sb.end.2 && footprint(brick.begin, brick.end).intersects(&footprint(sb.begin, sb.end));
+            if intersec {
+                sb.top.push(brick.id);
+                z = sb.end.2;
+                bottom_bricks.push(sb.id);
+            }
+        }
+
+        if !bottom_bricks.is_empty() {
+            let mut b = *brick;
+            b.end.2 = b.end.2 - b.begin.2 + z + 1;
+            b.begin.2 = z + 1;
+            let mut nsb = SettledBrick::new(&b);
+            nsb.bottom = bottom_bricks;
+
+            settled_bricks.push(nsb);
+        } else {
+            // the brick is in the ground
+            let mut nb = *brick;
+            if !(nb.begin.2 == 1 || nb.end.2 == 1) {
+                if nb.begin.2 == nb.end.2 {
+                    nb.begin.2 = 1;
+                    nb.end.2 = 1;
+                } else {
+                    nb.end.2 = nb.end.2 - nb.begin.2 + 1;
+                    nb.begin.2 = 1;
+                }
+            }
+            settled_bricks.push(SettledBrick::new(&nb));
+        }
+
+        settled_bricks.sort();
+    }
+
+    settled_bricks
+}
+
+fn disintegrable_bricks(bricks: &[SettledBrick]) -> HashMap<u32, &SettledBrick> {
+    let bricks_map: HashMap<u32, SettledBrick> = bricks.iter().map(|b| (b.id, b.clone())).collect();
+
+    let mut disintegrable_bricks_map = HashMap::new();
+
+    for b in bricks {
+        match b.top.len() {
+            ln if ln > 1 => {
+                let can_disintegrate = b.top.iter().map(|tid| bricks_map.get(tid).unwrap()).all(|bt| bt.bottom.len() > 1);
+                if can_disintegrate {
+                    disintegrable_bricks_map.insert(b.id, b);
+                }
+            },
+            1 => {
+                let tb = bricks_map.get(b.top.first().unwrap()).unwrap();
+                if tb.bottom.len() > 1 {
+                    disintegrable_bricks_map.insert(b.id, b);
+                }
+            },
+            _ => { // zero
+                disintegrable_bricks_map.insert(b.id, b);
+             }
+        };
+    }
+
+    disintegrable_bricks_map
+}
+
+fn count_fall_bricks(
+    sb: &SettledBrick, 
+    bricks_map: &HashMap<u32, SettledBrick>,
+    fall: &mut HashSet<u32>) {
+
+    for tsbid in &sb.top {
+        let tsb = bricks_map.get(tsbid).unwrap();
+        if tsb.bottom.len() > 1 {
+            // if a top brick is being hold by other bricks, we need to check if these will also fall
+            if tsb.bottom.iter().filter(|sbid| *sbid != tsbid).all(|id| fall.contains(id)) {
+                fall.insert(tsb.id);
+                count_fall_bricks(tsb, bricks_map, fall);
+            }
+        } else {
+            fall.insert(tsb.id);
+            count_fall_bricks(tsb, bricks_map, fall);
+        }
+    }
+}
+
+fn sum_fall_bricks(bricks: &[SettledBrick], disintegrable_bricks: &HashMap<u32, &SettledBrick>) -> usize {
+    let mut sum :usize = 0;
+
+    let bricks_map: HashMap<u32, SettledBrick> = bricks.iter().map(|b| (b.id, b.clone())).collect();
+    
+    for sb in bricks {
+        if !disintegrable_bricks.contains_key(&sb.id) {
+            let mut fall = HashSet::new();
+            count_fall_bricks(sb, &bricks_map, &mut fall);
+            sum += fall.len();
+        }
+    }
+
+    sum
+}
+
+// The bricks seen from the front (x, z) and from the side (y, z) next to each other, over the brown ground at z = 0
+fn side_views(bricks: &[Brick], (max_x, max_y, max_z): (u32, u32, u32)) -> Frame {
+    let (width_x, width_y, height) = (max_x as usize + 1, max_y as usize + 1, max_z as usize + 1);
+    let mut cells = Grid::from_fn(width_x + 1 + width_y, height, |(_, row)| if row == height - 1 { Color::BROWN } else { Color::BLACK });
+
+    for brick in bricks {
+        let color = PALETTE[brick.id as usize % PALETTE.len()];
+        let (begin, end) = (brick.begin, brick.end);
+
+        for z in begin.2.min(end.2)..=begin.2.max(end.2) {
+            let row = height - 1 - z as usize;
+            for x in begin.0.min(end.0)..=begin.0.max(end.0) {
+                cells[(x as usize, row)] = color;
+            }
+            for y in begin.1.min(end.1)..=begin.1.max(end.1) {
+                cells[(width_x + 1 + y as usize, row)] = color;
+            }
+        }
+    }
+
+    Frame { name: "falling".to_string(), cells }
+}
+
+// `coords` is a slice of the brick line `text`
+fn parse_coords(text: &str, coords: &str) -> ParseResult<(u32, u32, u32)> {
+    let [x, y, z] = input::exactly(text, coords, input::separated(text, coords, ',', "coordinate")?, "coordinates")?;
+
+    Ok((x, y, z))
+}
+
+fn parse_brick(id: u32, text: &str) -> ParseResult<Brick> {
+    let (begin, end) = input::split_once(text, text, "~", "the brick end")?;
+    let begin = parse_coords(text, begin)?;
+    let end = parse_coords(text, end)?;
+
+    // the ends can come either way round, the settling needs the lower one first
+    let (begin, end) = if begin.2 <= end.2 { (begin, end) } else { (end, begin) };
+
+    Ok(Brick {id, begin, end})
+}
+
+pub struct SandSlabs;
+
+impl Solution for SandSlabs {
+    type Input = Vec<Brick>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PART1: &'static str = "Number of bricks that can be safely disintegrated";
+    const PART2: &'static str = "Sum of the number of other bricks that would fall for each disintegrated brick";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let mut bricks: Vec<Brick> = vec![];
+
+        for (brick_id, text) in input.lines().enumerate() {
+            let brick = parse_brick(brick_id as u32, text).map_err(|e| e.on_line(brick_id + 1))?;
+            bricks.push(brick);
+        }
+
+        bricks.sort();
+
+        Ok(bricks)
+    }
+
+    fn part1(bricks: &Self::Input) -> usize {
+        let settled_bricks = settle_bricks(bricks);
+
+        disintegrable_bricks(&settled_bricks).len()
+    }
+
+    fn part2(bricks: &Self::Input) -> usize {
+        let settled_bricks = settle_bricks(bricks);
+        let disintegrable_bricks = disintegrable_bricks(&settled_bricks);
+
+        sum_fall_bricks(&settled_bricks, &disintegrable_bricks)
+    }
+
+    // the bricks falling into place from the lowest one up
+    fn animate(bricks: &Self::Input) -> Vec<Frame> {
+        let settled: HashMap<u32, Brick> = settle_bricks(bricks).into_iter()
+            .map(|sb| (sb.id, Brick { id: sb.id, begin: sb.begin, end: sb.end }))
+            .collect();
+
+        let size = bricks.iter().fold((0, 0, 0), |(x, y, z), b| {
+            (x.max(b.begin.0).max(b.end.0), y.max(b.begin.1).max(b.end.1), z.max(b.begin.2).max(b.end.2))
+        });
+
+        animate::checkpoints(bricks.len()).into_iter()
+            .map(|fallen| {
+                let current: Vec<Brick> = bricks.iter().enumerate()
+                    .map(|(i, b)| if i < fallen { settled[&b.id] } else { *b })
+                    .collect();
+                side_views(&current, size)
+            })
+            .collect()
+    }
+}
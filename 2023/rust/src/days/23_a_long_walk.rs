@@ -0,0 +1,235 @@
+use crate::input::{ParseError, ParseResult};
+use crate::render::{Color, Frame};
+use crate::solution::Solution;
+use crate::utils::direction::Direction;
+use crate::utils::grid::Grid;
+use crate::utils::search;
+
+use indexmap::IndexSet;
+use std::collections::{HashMap,HashSet};
+
+// junction -> [(x, y, steps)]
+type Graph = HashMap<(usize, usize), Vec<(usize, usize, usize)>>;
+
+// a step in `dir` that is not into a forest, nor up a slope when they are slippery
+fn check_direction(pos: (usize, usize), dir: Direction, slopes: bool, grid: &Grid<char>) -> Option<(usize, usize)> {
+    grid.step(pos, dir).filter(|p| {
+        let ch = grid[*p];
+        ch != '#' && !(slopes && Direction::from_arrow(ch) == Some(dir.opposite()))
+    })
+}
+
+fn neighbors(pos: (usize, usize), slopes: bool, grid: &Grid<char>) -> [Option<(usize, usize)>; 4] {
+    Direction::ALL.map(|dir| check_direction(pos, dir, slopes, grid))
+}
+
+fn walk(mut path: IndexSet<(usize, usize)>, slopes: bool, grid: &Grid<char>) -> IndexSet<(usize, usize)> {
+    let mut pos = *path.last().unwrap();
+    
+    let mut can_walk = true;
+    while can_walk {
+        let ch = grid[pos];
+
+        let choices: Vec<(usize, usize)> = match Direction::from_arrow(ch).filter(|_| slopes) {
+            Some(slope) => grid.step(pos, slope).into_iter().collect(),
+            None => {
+                let neighbors = neighbors(pos, slopes, grid);
+        
+                neighbors.iter()
+                    .filter(|n| n.is_some() && !path.contains(&n.unwrap()))
+                    .map(|n|n.unwrap())
+                    .collect()
+            }
+        };
+
+        if !choices.is_empty() {
+            if choices.len() == 1 {
+                pos = *choices.first().unwrap();
+                path.insert(pos);
+            } else {
+                let mut lgst_fork_path = IndexSet::new();
+                for fork in choices {
+                    let mut npath = path.clone();
+                    npath.insert(fork);
+
+                    let fork_path = walk(npath, slopes, grid);
+
+                    let reached_dest = fork_path.last().is_some_and(|v|v.1 == grid.height() - 1);
+                    if reached_dest && fork_path.len() > lgst_fork_path.len() {
+                        lgst_fork_path = fork_path;
+                    }
+                }
+
+                path = lgst_fork_path;
+                can_walk = false;
+            }
+        } else {
+            can_walk = false;
+        }
+    }
+
+    path
+}
+
+// The entrance on the first row and the exit on the last one, parse checks there is one of each
+fn ends(grid: &Grid<char>) -> ((usize, usize), (usize, usize)) {
+    let last_row = grid.height() - 1;
+    let start = grid.row(0).iter().position(|ch| *ch == '.').unwrap();
+    let end = grid.row(last_row).iter().position(|ch| *ch == '.').unwrap();
+
+    ((start, 0), (end, last_row))
+}
+
+fn longest_path(grid: &Grid<char>, slopes: bool) -> IndexSet<(usize, usize)> {
+    let ((start, _), end) = ends(grid);
+
+    let mut path: IndexSet<(usize, usize)> = IndexSet::new();
+    path.insert((start, 0));
+    path.insert((start, 1));
+
+    // a walk stuck before the exit is no hike
+    let path = walk(path, slopes, grid);
+    if path.last() == Some(&end) { path } else { IndexSet::new() }
+}
+
+fn edge_contraction(grid: &Grid<char>) -> i32 {
+    let ((start, _), end) = ends(grid);
+
+    // first get junction points    
+    let mut junction_points = HashSet::new();
+    junction_points.insert((start, 0));
+    junction_points.insert(end);
+    
+    for (pos, ch) in grid.iter() {
+        if *ch != '#' {
+            let neighbors = neighbors(pos, false, grid);
+            
+            let neighbors_count = neighbors.iter().flatten().count();
+            if neighbors_count >= 3 {
+                junction_points.insert(pos);
+            }
+        }
+    }
+    
+    // build a weighted graph contracting edges 
+    let mut graph: Graph = HashMap::new();
+    
+    for &source in &junction_points {
+        // follow each trail leaving the junction until it reaches another one: two junctions can be
+        // linked by more than one trail, and the longest path may need the longer of them
+        for first in neighbors(source, false, grid).into_iter().flatten() {
+            let (mut previous, mut pos, mut n) = (source, first, 1);
+            while !junction_points.contains(&pos) {
+                match neighbors(pos, false, grid).into_iter().flatten().find(|next| *next != previous) {
+                    Some(next) => (previous, pos, n) = (pos, next, n + 1),
+                    None => break
+                }
+            }
+
+            if junction_points.contains(&pos) {
+                graph.entry(source).or_default().push((pos.0, pos.1, n));
+            }
+        }
+    }
+    
+    // calculate longest path using brute force
+    let mut seen: HashSet<(usize, usize)> = HashSet::new();
+    dfs((start, 0), end, &mut seen, &graph)
+
+}
+
+fn dfs(pos: (usize, usize), end: (usize ,usize), seen: &mut HashSet<(usize, usize)>, graph: &Graph) -> i32 {
+    if pos == end {
+        return 0;
+    }
+    
+    seen.insert(pos);
+    
+    let mut m = i32::MIN;
+
+    for nx in &graph[&pos] {
+        if !seen.contains(&(nx.0, nx.1)) {
+            let bm = dfs((nx.0, nx.1), end, seen, graph) + nx.2 as i32;
+            if bm > m {
+                m = bm;
+            }
+        }
+    }
+    seen.remove(&pos);
+
+    m
+}
+
+pub struct ALongWalk;
+
+impl Solution for ALongWalk {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    const PART1: &'static str = "Steps of the longest hike";
+    const PART2: &'static str = "Steps of the longest hike without slopes";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let grid = Grid::parse(input, |ch| ".#<>^v".contains(ch))?;
+        if grid.height() < 2 {
+            return Err(ParseError::new(1, 1, "the map needs a row for the entrance and one for the exit"));
+        }
+
+        for (y, what) in [(0, "entrance"), (grid.height() - 1, "exit")] {
+            let open: Vec<&char> = grid.row(y).iter().filter(|ch| **ch != '#').collect();
+            if open != [&'.'] {
+                return Err(ParseError::new(y + 1, 1, format!("expected a single '.' as the {}, found {} open tiles", what, open.len())));
+            }
+        }
+
+        // the hikes walk every way but through the forest, so without slopes the exit must be on one of them
+        let (start, end) = ends(&grid);
+        let path = search::bfs(start,
+            |&pos| grid.neighbours4(pos).filter(|p| grid[*p] != '#').collect::<Vec<_>>(),
+            |&pos| pos == end);
+        if path.is_none() {
+            return Err(ParseError::new(grid.height(), end.0 + 1, "the exit can't be reached from the entrance"));
+        }
+
+        Ok(grid)
+    }
+
+    // the slopes may keep every hike from the exit, there are no steps then
+    fn part1(grid: &Self::Input) -> usize {
+        longest_path(grid, true).len().saturating_sub(1)
+    }
+
+    // the part 1 algorithm will not finish in a reasonable amount of time
+    // we need to simplify the graph by removing edges that do not branch
+    // once again, based on HyperNeurtrino's solution: https://www.youtube.com/watch?v=NTLYL7Mg2jU
+    fn part2(grid: &Self::Input) -> i32 {
+        edge_contraction(grid)
+    }
+
+    // the part 1 hike in yellow, slopes in blue
+    fn render(grid: &Self::Input) -> Vec<Frame> {
+        let frame = Frame::new("hike", grid, |ch| match ch {
+                '#' => Color::DARK_GREY,
+                '.' => Color::BLACK,
+                _ => Color::BLUE
+            })
+            .overlay(longest_path(grid, true), Color::YELLOW);
+
+        vec![frame]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{agree, Shrink};
+
+    // the walk tile by tile only finishes on small maps without slopes
+    #[test]
+    fn contracted_graph_agrees_with_tile_walk() {
+        agree::<ALongWalk, i32>(23, &[20, 60], 30, Shrink::Size,
+            |grid| longest_path(grid, false).len() as i32 - 1,
+            edge_contraction);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::input::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::utils::geometry::{Line, Point};
+use crate::utils::math::{self, Rational};
+use rayon::prelude::*;
+
+const TEST_AREA: (u64, u64) = (200000000000000, 400000000000000);
+const EXAMPLE_TEST_AREA: (u64, u64) = (7, 27);
+
+type Vector = [i128; 3];
+
+#[derive(Debug)]
+pub struct Hailstone {
+    _id: u32,
+    position: Vector,
+    velocity: Vector
+}
+
+impl Hailstone {
+    // the path seen from above
+    fn path(&self) -> Line<i128> {
+        Line::new(Point::new(self.position[0], self.position[1]), Point::new(self.velocity[0], self.velocity[1]))
+    }
+}
+
+// the vector arithmetic is checked too, a wrapped product would give a wrong but plausible hit time
+fn sub(a: Vector, b: Vector) -> Vector {
+    [0, 1, 2].map(|i| math::checked(a[i].checked_sub(b[i])))
+}
+
+fn dot(a: Vector, b: Vector) -> i128 {
+    (0..3).fold(0, |sum, i| math::checked(math::checked(a[i].checked_mul(b[i])).checked_add(sum)))
+}
+
+fn cross(a: Vector, b: Vector) -> Vector {
+    // the determinant of [[a, b], [c, d]]
+    let det = |a: i128, b: i128, c: i128, d: i128| {
+        math::checked(math::checked(a.checked_mul(d)).checked_sub(math::checked(b.checked_mul(c))))
+    };
+
+    [det(a[1], a[2], b[1], b[2]), det(a[2], a[0], b[2], b[0]), det(a[0], a[1], b[0], b[1])]
+}
+
+// each hailstone is checked against the ones after it, in parallel
+fn count_intersections(test_area: (u64, u64), hailstones: &[Hailstone]) -> usize {
+    let (low, high) = (Rational::from(test_area.0 as i64), Rational::from(test_area.1 as i64));
+    let inside = |v: Rational| v >= low && v <= high;
+
+    (0..hailstones.len()).into_par_iter()
+        .map(|idx| {
+            let path = hailstones[idx].path();
+            hailstones[idx + 1..].iter()
+                .filter(|other| match path.crossing(&other.path()) {
+                    // the paths cross in the future of both hailstones
+                    Some(crossing) => {
+                        inside(crossing.point.x) && inside(crossing.point.y) &&
+                        crossing.t >= Rational::ZERO && crossing.u >= Rational::ZERO
+                    },
+                    // parallel paths (or the same path)
+                    None => false
+                })
+                .count()
+        })
+        .sum()
+}
+
+/**
+ * When the rock hits `a`, both given as (position, velocity) in the frame where the first hailstone stands still
+ * at the origin. There the rock goes through the origin and hits `b` too, so it hits `a` on the plane holding
+ * the origin and the path of `b`. None when that plane is not one, or `a` moves along it.
+ */
+fn hit_time(a: (Vector, Vector), b: (Vector, Vector)) -> Option<Rational> {
+    let normal = cross(b.0, b.1);
+    let den = dot(a.1, normal);
+
+    (den != 0).then(|| Rational::new(-dot(a.0, normal), den))
+}
+
+/**
+ * Where the rock starts, from the first hailstone and two others the rock hits at different times.
+ * Everything is exact: the times are rationals of i128s, and an overflow panics rather than giving a wrong throw.
+ */
+fn calculate_rock_position(hailstones: &[Hailstone]) -> [i128; 3] {
+    let h0 = &hailstones[0];
+    let relative = |h: &Hailstone| (sub(h.position, h0.position), sub(h.velocity, h0.velocity));
+    let hit = |(position, velocity): (Vector, Vector), t: Rational| {
+        [0, 1, 2].map(|i| Rational::from(position[i]) + t * Rational::from(velocity[i]))
+    };
+
+    // the first two usually do, unless they hit the first hailstone or share its plane
+    let pairs = (1..hailstones.len()).flat_map(|i| (i + 1..hailstones.len()).map(move |j| (i, j)));
+    for (i, j) in pairs {
+        let (a, b) = (relative(&hailstones[i]), relative(&hailstones[j]));
+        let (Some(ta), Some(tb)) = (hit_time(a, b), hit_time(b, a)) else {
+            continue;
+        };
+        if ta == tb {
+            continue;
+        }
+
+        let (pa, pb) = (hit(a, ta), hit(b, tb));
+        return [0, 1, 2].map(|i| {
+            let velocity = (pb[i] - pa[i]) / (tb - ta);
+            let start = pa[i] - ta * velocity + Rational::from(h0.position[i]);
+
+            start.to_integer().expect("the rock does not start at whole coordinates")
+        });
+    }
+
+    panic!("the hailstones do not give a single rock throw");
+}
+
+// `values` is a slice of the hailstone line `text`
+fn parse_vector(text: &str, values: &str, what: &str) -> ParseResult<Vector> {
+    input::exactly(text, values, input::ints(text, values, what)?, &format!("{} values", what))
+}
+
+fn parse_hailstone(id: u32, text: &str) -> ParseResult<Hailstone> {
+    let (position, velocity) = input::split_once(text, text, "@", "the velocity")?;
+    let position = parse_vector(text, position, "position")?;
+    let velocity = parse_vector(text, velocity, "velocity")?;
+
+    Ok(Hailstone { _id: id, position, velocity })
+}
+
+pub struct Hailstorm {
+    hailstones: Vec<Hailstone>,
+    // where part 1 looks for crossing paths, the same low and high bound for x and y
+    test_area: (u64, u64)
+}
+
+pub struct NeverTellMeTheOdds;
+
+impl Solution for NeverTellMeTheOdds {
+    type Input = Hailstorm;
+    type Answer1 = usize;
+    type Answer2 = i128;
+
+    const PART1: &'static str = "Number of intersections that occur within the test area";
+    const PART2: &'static str = "Sum of X, Y, and Z coordinates of the initial rock position";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let mut hailstones: Vec<Hailstone> = vec![];
+
+        for (hailstone_id, text) in input.lines().enumerate() {
+            let hailstone = parse_hailstone(hailstone_id as u32, text).map_err(|e| e.on_line(hailstone_id + 1))?;
+            hailstones.push(hailstone);
+        }
+
+        // the rock throw is only known from three of them
+        if hailstones.len() < 3 {
+            return Err(ParseError::new(hailstones.len() + 1, 1, format!("expected at least 3 hailstones, found {}", hailstones.len())));
+        }
+
+        Ok(Hailstorm { hailstones, test_area: TEST_AREA })
+    }
+
+    // the example hailstones start well before the real test area and come with their own
+    fn example(hailstorm: Self::Input) -> Self::Input {
+        Hailstorm { test_area: EXAMPLE_TEST_AREA, ..hailstorm }
+    }
+
+    fn part1(hailstorm: &Self::Input) -> usize {
+        count_intersections(hailstorm.test_area, &hailstorm.hailstones)
+    }
+
+    fn part2(hailstorm: &Self::Input) -> i128 {
+        calculate_rock_position(&hailstorm.hailstones).iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector_products() {
+        assert_eq!(dot([1, 2, 3], [4, -5, 6]), 12);
+        assert_eq!(cross([1, 0, 0], [0, 1, 0]), [0, 0, 1]);
+        assert_eq!(cross([2, 3, 4], [5, 6, 7]), [-3, 6, -3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in rational arithmetic")]
+    fn cross_product_overflow_panics() {
+        cross([0, i128::MAX / 2, 0], [0, 0, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in rational arithmetic")]
+    fn hit_time_overflow_panics() {
+        let big = 1 << 100;
+        hit_time(([big, 0, 0], [1, 1, 1]), ([0, big, 0], [0, 0, big]));
+    }
+}
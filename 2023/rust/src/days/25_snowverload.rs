@@ -0,0 +1,262 @@
+/*!
+ * Finding the minimum cut: <https://en.wikipedia.org/wiki/Minimum_cut>.
+ * 
+ * This is based on @maneatingape's solution: 
+ *  - <https://github.com/maneatingape/advent-of-code-rust/blob/main/src/year2023/day25.rs>
+ *  - <https://bit.ly/3SUvYhv>
+ * 
+ * If we find the begin and end nodes of the graph (where the distance between begin and end is greater), 
+ * and we know that there are 3 edges that can be cut to form two distinct sub-graphs, then if we go
+ * from the begin to the end nodes using 3 different paths, no other paths can reach the end node. 
+ * The 4th iteration only reaches the the nodes in the first sub-graph. We can then compute the final 
+ * answer.
+ * 
+ * Although this is a deterministic solution, it assumes that the minimum cut results in two graphs 
+ * with similar number of nodes, which seems to be the case of the aoc inputs.
+ * 
+ * ```text
+ *           * *       * *
+ *         * * * * - * * * *
+ *       S * * * * - * * * * E
+ *         * * * * - * * * *
+ *           * *       * *
+ * ```
+ * 
+ * However, if this was not the case, this solution will fail as the start and end nodes might be in 
+ * the same sub-graph.
+ * 
+ * ```text
+ *                S *
+ *              * * * *
+ *              * * * *
+ *              * * * *
+ *              * * * *
+ *        * * - * * * * *
+ *      * * * - A * * * * *
+ *        * * - * * * * *
+ *              * * * *
+ *              * * * *
+ *              * * * *
+ *              * * * *
+ *                * E
+ * ```
+ *      
+ * The small example is like this for some starting nodes, so when the group found is not cut by
+ * exactly 3 edges we start again from the next node.
+ * 
+ * I've included a first naive attempt (split_components2, in the tests) which is a brute force approach where the 
+ * combination of all edges are considered. It checks this solution on small generated graphs.
+ * 
+ */
+
+use crate::input::{self, ParseError, ParseResult};
+use crate::solution::Solution;
+use crate::utils::search;
+
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug, Copy, Clone, Eq)]
+struct Edge<'a> {
+    a: &'a str,
+    b: &'a str
+}
+
+// implement Link equality independent of a,b order
+impl<'a> PartialEq for Edge<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.a == other.a &&
+        self.b == other.b) || 
+        (self.a == other.b &&
+        self.b == other.a)
+    }
+}
+
+// hash must agree with the order independent equality
+impl<'a> Hash for Edge<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.a.min(self.b).hash(state);
+        self.a.max(self.b).hash(state);
+    }
+}
+
+fn split_components(map: &HashMap<String, Vec<String>>) -> usize {
+
+    // the furthest nodes can end up in the same sub-graph (see above), so keep trying from other
+    // nodes until the group found is cut from the rest by exactly 3 edges. The names are sorted
+    // so the result does not depend on the HashMap order
+    let mut names: Vec<&String> = map.keys().collect();
+    names.sort();
+
+    for k in names {
+        // arbitrarily pick a node from the graph and then find the furthest node from it
+        let start = furthest(k, map);
+        // find the furthest node from the start node
+        let end = furthest(start, map);
+        
+        // Iterate through the graph 3 times to find the min-cut. The 4th iteration will give us 
+        // the nodes in the first sub-graph
+        let group = process(start, end, map);
+
+        let cut = group.iter()
+            .flat_map(|comp| map[*comp].iter().filter(|next| !group.contains(next.as_str())))
+            .count();
+        if cut == 3 {
+            return group.len() * (map.len() - group.len());
+        }
+    }
+
+    0
+}
+
+/// BFS across the graph to find the furthest nodes from start.
+fn furthest<'a>(start: &'a str, map: &'a HashMap<String, Vec<String>>) -> &'a str {
+    let reachable = search::reachable(start, |current| map[*current].iter().map(String::as_str), None);
+
+    // The last node visited will be the furthest.
+    reachable.last().map_or(start, |(node, _)| node)
+}
+ 
+fn process<'a>(start: &'a str, end: &'a str, map: &'a HashMap<String, Vec<String>>) -> HashSet<&'a str> {
+    let mut used: HashSet<Edge> = HashSet::new();
+
+    // the edges of the graph that were not used by a previous path
+    let unused_links = |current: &&str, used: &HashSet<Edge>| -> Vec<&str> {
+        map[*current].iter()
+            .filter(|next| !used.contains(&Edge {a: current, b: next}))
+            .map(String::as_str)
+            .collect()
+    };
+
+    // As the minimum cut is 3, three paths from start to end use all edges of the cut.
+    // Store them in the used HashSet of edges so each edge is used only once
+    for _ in 0..3 {
+        let path = search::bfs(start, |current| unused_links(current, &used), |current| *current == end);
+
+        if let Some(path) = path {
+            used.extend(path.states.windows(2).map(|link| Edge {a: link[0], b: link[1]}));
+        }
+    }
+
+    // the 4th iteration will only be able to reach the nodes in the first sub-graph
+    search::reachable(start, |current| unused_links(current, &used), None).into_iter()
+        .map(|(comp, _)| comp)
+        .collect()
+}
+
+pub struct Snowverload;
+
+impl Solution for Snowverload {
+    type Input = HashMap<String, Vec<String>>;
+    type Answer1 = usize;
+    type Answer2 = &'static str;
+
+    const PART1: &'static str = "Product of disconnected groups";
+    // day 25 only has one puzzle, the second star is given away
+    const PART2: &'static str = "Push the big red button";
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let mut map: HashMap<String, Vec<String>> = HashMap::new();
+
+        let lines = input::parse_lines(input, |text| {
+            let (comp, links) = input::split_once(text, text, ":", "the linked components")?;
+            if comp.is_empty() || links.is_empty() {
+                return Err(ParseError::at(text, text, "expected '<component>: <component> ...'"));
+            }
+
+            Ok((comp, links))
+        })?;
+
+        for (comp, comp_links) in lines {
+            for l in comp_links.split_whitespace() {
+                map.entry(comp.to_string()).or_default().push(l.to_string());
+                map.entry(l.to_string()).or_default().push(comp.to_string());
+            }
+        }
+
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input) -> usize {
+        split_components(map)
+    }
+
+    fn part2(_: &Self::Input) -> &'static str {
+        "Merry Christmas!"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{agree, Shrink};
+
+    use indexmap::IndexSet;
+
+    // dumb solution (only works for small inputs)
+    fn split_components2(map: &HashMap<String, Vec<String>>) -> usize {
+        // get all links
+        let mut links: IndexSet<Edge> = IndexSet::new();
+
+        for (comp, comp_links) in map {
+            for l in comp_links {
+                links.insert(Edge {a: comp, b: l});
+            }
+        }
+
+        for i in 0..links.len() {
+            for j in (i + 1)..links.len() {
+                for k in (j + 1)..links.len() {
+                    let l1 = links.get_index(i).unwrap();
+                    let l2 = links.get_index(j).unwrap();
+                    let l3 = links.get_index(k).unwrap();
+
+                    let reachable = check_broken_chain((l1, l2, l3), map);
+                    if reachable != map.len() {
+                        return reachable * (map.len() - reachable);
+                    }
+                }
+            }
+        }
+
+        0
+    }
+
+    // part of dumb solution
+    fn check_broken_chain(
+        (l1, l2, l3): (&Edge, &Edge, &Edge), 
+        components: &HashMap<String, Vec<String>>) -> usize {
+
+        // start element
+        let mut start = None;
+        for (k, links) in components {
+            for l in links {
+                let link = Edge {a: k, b: l};
+                if link.ne(l1) && link.ne(l2) && link.ne(l3) {
+                    start = Some(k);
+                    break;
+                }
+            }
+            if start.is_some() {
+                break;
+            }
+        }
+
+        let reachable = search::reachable(start.unwrap(), |&comp| {
+            components[comp].iter().filter(move |l| {
+                let link = Edge {a: comp, b: l};
+                link.ne(l1) && link.ne(l2) && link.ne(l3)
+            })
+        }, None);
+
+        reachable.len()
+    }
+
+    // the brute force tries every 3 wires, so only the smallest graphs
+    #[test]
+    fn min_cut_agrees_with_brute_force() {
+        agree::<Snowverload, usize>(25, &[14, 20], 4, Shrink::Size,
+            split_components,
+            split_components2);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::solution;
+use crate::solution::{Answer, Puzzle};
+use crate::input::ParseResult;
+use crate::render::Frame;
+
+use std::path::Path;
+use std::time::Duration;
+
+#[path = "01_trebuchet.rs"]
+pub mod d01;
+#[path = "02_cube_conundrum.rs"]
+pub mod d02;
+#[path = "03_gear_ratios.rs"]
+pub mod d03;
+#[path = "04_scratchcards.rs"]
+pub mod d04;
+#[path = "05_seed_fertilizer.rs"]
+pub mod d05;
+#[path = "06_wait_for_it.rs"]
+pub mod d06;
+#[path = "07_camel_cards.rs"]
+pub mod d07;
+#[path = "08_haunted_wasteland.rs"]
+pub mod d08;
+#[path = "09_mirage_maintenance.rs"]
+pub mod d09;
+#[path = "10_pipe_maze.rs"]
+pub mod d10;
+#[path = "11_cosmic_expansion.rs"]
+pub mod d11;
+#[path = "12_hot_springs.rs"]
+pub mod d12;
+#[path = "13_point_of_incidence.rs"]
+pub mod d13;
+#[path = "14_parabolic_reflector_dish.rs"]
+pub mod d14;
+#[path = "15_lens_library.rs"]
+pub mod d15;
+#[path = "16_the_floor_will_be_lava.rs"]
+pub mod d16;
+#[path = "17_clumsy_crucible.rs"]
+pub mod d17;
+#[path = "18_lavaduct_lagoon.rs"]
+pub mod d18;
+#[path = "19_aplenty.rs"]
+pub mod d19;
+#[path = "20_pulse_propagation.rs"]
+pub mod d20;
+#[path = "21_step_counter.rs"]
+pub mod d21;
+#[path = "22_sand_slabs.rs"]
+pub mod d22;
+#[path = "23_a_long_walk.rs"]
+pub mod d23;
+#[path = "24_never_tell_me_the_odds.rs"]
+pub mod d24;
+#[path = "25_snowverload.rs"]
+pub mod d25;
+
+pub struct Day {
+    pub day: u8,
+    pub name: &'static str,
+    pub solve: fn(&str, Option<u8>, Puzzle) -> ParseResult<Vec<Answer>>,
+    pub render: fn(&str, Puzzle) -> ParseResult<Vec<Frame>>,
+    pub animate: fn(&str, Puzzle) -> ParseResult<Vec<Frame>>,
+    pub time: fn(&str, Puzzle) -> ParseResult<[Duration; 3]>
+}
+
+impl Day {
+    pub fn input_file(&self) -> String {
+        format!("./input/{:02}_input.txt", self.day)
+    }
+
+    // the first example has no number, e.g. 08_input_test.txt, 08_input_test2.txt, ...
+    pub fn example_file(&self, n: u8) -> String {
+        if n == 1 {
+            format!("./input/{:02}_input_test.txt", self.day)
+        } else {
+            format!("./input/{:02}_input_test{}.txt", self.day, n)
+        }
+    }
+
+    // the puzzle a file holds the input of, from its name: the examples are the NN_input_test*.txt files
+    pub fn puzzle_of(&self, file: &str) -> Puzzle {
+        let name = Path::new(file).file_name().and_then(|name| name.to_str()).unwrap_or_default();
+        let is_example = name.strip_prefix(&format!("{:02}_input_test", self.day))
+            .and_then(|rest| rest.strip_suffix(".txt"))
+            .is_some_and(|n| n.chars().all(|ch| ch.is_ascii_digit()));
+
+        if is_example { Puzzle::Example } else { Puzzle::Real }
+    }
+}
+
+macro_rules! day {
+    ($day:expr, $name:expr, $solution:ty) => {
+        Day {
+            day: $day,
+            name: $name,
+            solve: solution::solve::<$solution>,
+            render: solution::render::<$solution>,
+            animate: solution::animate::<$solution>,
+            time: solution::time::<$solution>
+        }
+    };
+}
+
+pub const DAYS: [Day; 25] = [
+    day!(1, "trebuchet", d01::Trebuchet),
+    day!(2, "cube_conundrum", d02::CubeConundrum),
+    day!(3, "gear_ratios", d03::GearRatios),
+    day!(4, "scratchcards", d04::Scratchcards),
+    day!(5, "seed_fertilizer", d05::SeedFertilizer),
+    day!(6, "wait_for_it", d06::WaitForIt),
+    day!(7, "camel_cards", d07::CamelCards),
+    day!(8, "haunted_wasteland", d08::HauntedWasteland),
+    day!(9, "mirage_maintenance", d09::MirageMaintenance),
+    day!(10, "pipe_maze", d10::PipeMaze),
+    day!(11, "cosmic_expansion", d11::CosmicExpansion),
+    day!(12, "hot_springs", d12::HotSprings),
+    day!(13, "point_of_incidence", d13::PointOfIncidence),
+    day!(14, "parabolic_reflector_dish", d14::ParabolicReflectorDish),
+    day!(15, "lens_library", d15::LensLibrary),
+    day!(16, "the_floor_will_be_lava", d16::TheFloorWillBeLava),
+    day!(17, "clumsy_crucible", d17::ClumsyCrucible),
+    day!(18, "lavaduct_lagoon", d18::LavaductLagoon),
+    day!(19, "aplenty", d19::Aplenty),
+    day!(20, "pulse_propagation", d20::PulsePropagation),
+    day!(21, "step_counter", d21::StepCounter),
+    day!(22, "sand_slabs", d22::SandSlabs),
+    day!(23, "a_long_walk", d23::ALongWalk),
+    day!(24, "never_tell_me_the_odds", d24::NeverTellMeTheOdds),
+    day!(25, "snowverload", d25::Snowverload),
+];
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
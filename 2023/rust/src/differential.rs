@@ -0,0 +1,133 @@
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::generate;
+use crate::solution::Solution;
+use crate::verify;
+
+// How a failing input is made smaller before it is reported
+pub enum Shrink {
+    // the smallest generated size that fails is reported as it is
+    Size,
+    // lines are also dropped while the implementations still disagree, for inputs whose lines stand on their own
+    Lines
+}
+
+// what an implementation returned, or why it panicked
+fn run<I, T>(input: &I, implementation: &impl Fn(&I) -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| implementation(input)))
+        .map_err(|payload| format!("panicked: {}", verify::panic_message(payload.as_ref())))
+}
+
+// None when both implementations give the same answer
+fn disagreement<I, T: PartialEq + Debug>(
+    input: &I,
+    a: &impl Fn(&I) -> T,
+    b: &impl Fn(&I) -> T) -> Option<(Result<T, String>, Result<T, String>)> {
+
+    let (x, y) = (run(input, a), run(input, b));
+
+    (x != y).then_some((x, y))
+}
+
+// Drops chunks of lines, then single lines, as long as the input still fails
+fn shrink_lines(text: &str, fails: impl Fn(&str) -> bool) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let mut chunk = (lines.len() / 2).max(1);
+
+    while chunk > 0 {
+        let mut removed = false;
+        let mut i = 0;
+        while i < lines.len() {
+            let candidate: Vec<&str> = lines[..i].iter().chain(&lines[(i + chunk).min(lines.len())..]).copied().collect();
+            if !candidate.is_empty() && fails(&(candidate.join("\n") + "\n")) {
+                lines = candidate;
+                removed = true;
+            } else {
+                i += chunk;
+            }
+        }
+        if !removed {
+            chunk /= 2;
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/**
+ * Checks that two implementations of the same thing agree on generated inputs of the day, every seed below `seeds`
+ * at every size from the smallest one. A panic counts as an answer, so one implementation panicking is a failure too.
+ * Panics with the smallest input they disagree on.
+ */
+pub fn agree<S: Solution, T: PartialEq + Debug>(
+    day: u8,
+    sizes: &[usize],
+    seeds: u64,
+    shrink: Shrink,
+    a: impl Fn(&S::Input) -> T,
+    b: impl Fn(&S::Input) -> T) {
+
+    for &size in sizes {
+        for seed in 0..seeds {
+            let text = generate::generate(day, seed, size).expect("no generator for the day").input;
+            // a generator must only make valid inputs, or the implementations would never be compared
+            let input = S::parse(&text).unwrap_or_else(|e| {
+                panic!("day {:02} generated seed {}, size {} is not a valid input: {}\n{}", day, seed, size, e, text)
+            });
+            if disagreement(&input, &a, &b).is_none() {
+                continue;
+            }
+
+            let text = match shrink {
+                Shrink::Size => text,
+                Shrink::Lines => shrink_lines(&text, |candidate| {
+                    // a candidate that does not parse, or where either side panics, is a different bug, not a smaller one
+                    S::parse(candidate).ok()
+                        .and_then(|input| disagreement(&input, &a, &b))
+                        .is_some_and(|(x, y)| x.is_ok() && y.is_ok())
+                })
+            };
+            let (x, y) = disagreement(&S::parse(&text).unwrap(), &a, &b).unwrap();
+
+            panic!("day {:02} implementations disagree on generated seed {}, size {}: {:?} != {:?}, shrunk input:\n{}",
+                day, seed, size, x, y, text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{ParseError, ParseResult};
+
+    // stands for a day whose generator makes inputs it can't parse
+    struct Rejects;
+
+    impl Solution for Rejects {
+        type Input = ();
+        type Answer1 = u8;
+        type Answer2 = u8;
+
+        const PART1: &'static str = "";
+        const PART2: &'static str = "";
+
+        fn parse(_input: &str) -> ParseResult<()> {
+            Err(ParseError::new(1, 1, "rejected"))
+        }
+
+        fn part1(_input: &()) -> u8 {
+            0
+        }
+
+        fn part2(_input: &()) -> u8 {
+            0
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "day 01 generated seed 0, size 5 is not a valid input: 1:1: rejected")]
+    fn generated_inputs_must_parse() {
+        agree::<Rejects, u8>(1, &[5], 1, Shrink::Size, Rejects::part1, Rejects::part2);
+    }
+}
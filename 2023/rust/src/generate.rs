@@ -0,0 +1,992 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt::{Display, Write as _};
+
+use crate::utils::direction::Direction;
+use crate::utils::grid::Grid;
+use crate::utils::math;
+use crate::utils::rng::Rng;
+
+pub const DEFAULT_SEED: u64 = 1;
+pub const DEFAULT_SIZE: usize = 100;
+
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/**
+ * A random puzzle input, along with the answers it was built to have when the generator knows them
+ * (e.g. the planted minimum cut of day 25), so `generate --check` can tell a wrong answer from a right one.
+ */
+pub struct Generated {
+    pub input: String,
+    pub answers: [Option<String>; 2]
+}
+
+impl Generated {
+    fn new(input: String) -> Self {
+        Self { input, answers: [None, None] }
+    }
+
+    fn answer(mut self, part: usize, value: impl Display) -> Self {
+        self.answers[part - 1] = Some(value.to_string());
+        self
+    }
+}
+
+/**
+ * Builds an input for a day from the random numbers, `size` is roughly the number of lines, or the side of the grid.
+ */
+type Generator = fn(&mut Rng, usize) -> Generated;
+
+const GENERATORS: [Generator; 25] = [
+    trebuchet, cube_conundrum, gear_ratios, scratchcards, seed_fertilizer,
+    wait_for_it, camel_cards, haunted_wasteland, mirage_maintenance, pipe_maze,
+    cosmic_expansion, hot_springs, point_of_incidence, parabolic_reflector_dish, lens_library,
+    the_floor_will_be_lava, clumsy_crucible, lavaduct_lagoon, aplenty, pulse_propagation,
+    step_counter, sand_slabs, a_long_walk, never_tell_me_the_odds, snowverload
+];
+
+// A random input for the day, always the same one for the same seed and size
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<Generated> {
+    let generator = GENERATORS.get((day as usize).checked_sub(1)?)?;
+
+    Some(generator(&mut Rng::new(seed), size))
+}
+
+fn join<T: Display>(items: impl IntoIterator<Item = T>, separator: &str) -> String {
+    items.into_iter().map(|item| item.to_string()).collect::<Vec<_>>().join(separator)
+}
+
+fn word(rng: &mut Rng, len: usize, letters: &[u8]) -> String {
+    (0..len).map(|_| *rng.pick(letters) as char).collect()
+}
+
+// `count` different words of `len` letters, none of them in `taken`
+fn unique_words(rng: &mut Rng, count: usize, len: usize, letters: &[u8], taken: &[&str]) -> Vec<String> {
+    assert!(count + taken.len() <= letters.len().pow(len as u32), "there are not {} words of {} letters", count, len);
+
+    let mut seen: HashSet<String> = taken.iter().map(|t| t.to_string()).collect();
+    let mut words = vec![];
+    while words.len() < count {
+        let w = word(rng, len, letters);
+        if seen.insert(w.clone()) {
+            words.push(w);
+        }
+    }
+
+    words
+}
+
+/**
+ * A random shape of `width` x `height` cells without holes, whose cells never touch only by a corner,
+ * so its outline is a simple loop.
+ */
+fn polyomino(rng: &mut Rng, width: usize, height: usize) -> Grid<bool> {
+    let mut cells = Grid::from_fn(width, height, |_| false);
+    let start = (width / 2, height / 2);
+    cells[start] = true;
+
+    let mut region = vec![start];
+    let target = (width * height * 2 / 5).max(1);
+    for _ in 0..target * 50 {
+        if region.len() >= target {
+            break;
+        }
+
+        let from = *rng.pick(&region);
+        let dir = *rng.pick(&Direction::ALL);
+        if let Some(pos) = cells.step(from, dir) {
+            if !cells[pos] && keeps_simple(&cells, pos) {
+                cells[pos] = true;
+                region.push(pos);
+            }
+        }
+    }
+
+    cells
+}
+
+// Whether filling `pos` keeps the shape simple: its filled neighbours must be a single run around it
+fn keeps_simple(cells: &Grid<bool>, pos: (usize, usize)) -> bool {
+    const RING: [(i64, i64); 8] = [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)];
+
+    let filled = RING.map(|offset| cells.offset(pos, offset).is_some_and(|p| cells[p]));
+
+    (0..8).filter(|&i| filled[i] && !filled[(i + 7) % 8]).count() == 1
+}
+
+/**
+ * The outline of a `polyomino` clockwise, as the cell corners it goes through (from (0, 0) to (width, height))
+ * and the direction it leaves each of them. It starts at the top left corner of the first filled cell.
+ */
+fn outline(cells: &Grid<bool>) -> Vec<((usize, usize), Direction)> {
+    let filled = |(x, y): (i64, i64)| cells.checked_pos((x, y)).is_some_and(|p| cells[p]);
+
+    // the side of a cell from corner (x, y) in `dir`, when it has a filled cell on one side only
+    let on_outline = |(x, y): (i64, i64), dir: Direction| {
+        let (a, b) = match dir {
+            Direction::Right => ((x, y - 1), (x, y)),
+            Direction::Left => ((x - 1, y - 1), (x - 1, y)),
+            Direction::Down => ((x - 1, y), (x, y)),
+            Direction::Up => ((x - 1, y - 1), (x, y - 1))
+        };
+        filled(a) != filled(b)
+    };
+
+    let (sx, sy) = cells.find(&true).expect("the shape has no cells");
+    let start = (sx as i64, sy as i64);
+
+    let mut corners = vec![];
+    let (mut pos, mut dir) = (start, Direction::Right);
+    loop {
+        corners.push(((pos.0 as usize, pos.1 as usize), dir));
+
+        let (dx, dy) = dir.offset();
+        pos = (pos.0 + dx, pos.1 + dy);
+        if pos == start {
+            break;
+        }
+        dir = [dir.turn_left(), dir, dir.turn_right()].into_iter()
+            .find(|d| on_outline(pos, *d))
+            .expect("the shape outline is not a simple loop");
+    }
+
+    corners
+}
+
+// (the value at 0, ...) of a polynomial given by the differences at 0, like the day 9 triangles
+fn newton_values(differences: &[i64], count: usize) -> Vec<i64> {
+    let mut values = vec![];
+    let mut level = differences.to_vec();
+    for _ in 0..count {
+        values.push(level[0]);
+        for i in 0..level.len() - 1 {
+            level[i] += level[i + 1];
+        }
+    }
+
+    values
+}
+
+// 1: letters, digits and spelled digits (some of them sharing letters), with at least one digit on every line
+fn trebuchet(rng: &mut Rng, size: usize) -> Generated {
+    const SPELLED: [&str; 17] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        "oneight", "twone", "threeight", "fiveight", "sevenine", "eightwo", "eighthree", "nineight"
+    ];
+
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut pieces = vec![];
+        for _ in 0..rng.between(1, 6) {
+            let piece = match rng.below(3) {
+                0 => {
+                    let len = rng.between(1, 4) as usize;
+                    word(rng, len, LOWERCASE)
+                },
+                1 => rng.between(1, 9).to_string(),
+                _ => rng.pick(&SPELLED).to_string()
+            };
+            pieces.push(piece);
+        }
+        if !pieces.iter().any(|p| p.starts_with(|ch: char| ch.is_ascii_digit())) {
+            let at = rng.below(pieces.len() + 1);
+            pieces.insert(at, rng.between(1, 9).to_string());
+        }
+
+        writeln!(input, "{}", pieces.concat()).unwrap();
+    }
+
+    Generated::new(input)
+}
+
+// 2: up to 6 draws per game of up to 20 cubes of each color
+fn cube_conundrum(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    for id in 1..=size {
+        let mut draws = vec![];
+        for _ in 0..rng.between(1, 6) {
+            let mut colors = ["red", "green", "blue"];
+            rng.shuffle(&mut colors);
+            let count = rng.between(1, 3) as usize;
+            let cubes: Vec<String> = colors[..count].iter().map(|color| format!("{} {}", rng.between(1, 20), color)).collect();
+            draws.push(cubes.join(", "));
+        }
+
+        writeln!(input, "Game {}: {}", id, draws.join("; ")).unwrap();
+    }
+
+    Generated::new(input)
+}
+
+// 3: a square schematic with numbers up to 999, most of them next to a symbol and many of those a '*'
+fn gear_ratios(rng: &mut Rng, size: usize) -> Generated {
+    const SYMBOLS: [char; 9] = ['#', '+', '$', '/', '=', '%', '@', '&', '-'];
+
+    let side = size.max(3);
+    let mut grid = Grid::from_fn(side, side, |_| '.');
+
+    let mut numbers = vec![];
+    for _ in 0..side * side / 12 {
+        let digits = rng.between(1, 999).to_string();
+        let len = digits.len();
+        if len > side {
+            continue;
+        }
+
+        // with a gap on both sides, so it does not run into another number
+        let (x, y) = (rng.below(side - len + 1), rng.below(side));
+        if (x.saturating_sub(1)..(x + len + 1).min(side)).all(|c| grid[(c, y)] == '.') {
+            for (i, ch) in digits.chars().enumerate() {
+                grid[(x + i, y)] = ch;
+            }
+            numbers.push((x, y, len));
+        }
+    }
+
+    for (x, y, len) in numbers {
+        let around: Vec<(usize, usize)> = (x as i64 - 1..=(x + len) as i64)
+            .flat_map(|c| (y as i64 - 1..=y as i64 + 1).map(move |r| (c, r)))
+            .filter_map(|p| grid.checked_pos(p))
+            .filter(|p| grid[*p] == '.')
+            .collect();
+
+        if !around.is_empty() && rng.chance(0.85) {
+            let pos = *rng.pick(&around);
+            grid[pos] = if rng.chance(0.4) { '*' } else { *rng.pick(&SYMBOLS) };
+        }
+    }
+
+    Generated::new(grid.to_string())
+}
+
+// 4: mostly losing cards, so the copies do not grow out of hand, and never winning copies past the last card
+fn scratchcards(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut points = 0;
+    let mut copies = vec![1; size];
+
+    for id in 0..size {
+        let matches = if rng.chance(0.85) { 0 } else { (rng.between(1, 10) as usize).min(size - 1 - id) };
+
+        let mut numbers: Vec<u32> = (1..100).collect();
+        rng.shuffle(&mut numbers);
+        let (winning, losing) = numbers.split_at(10);
+        let mut have: Vec<u32> = winning[..matches].iter().chain(&losing[..25 - matches]).copied().collect();
+        rng.shuffle(&mut have);
+
+        writeln!(input, "Card {:>3}: {} | {}",
+            id + 1,
+            join(winning.iter().map(|n| format!("{:>2}", n)), " "),
+            join(have.iter().map(|n| format!("{:>2}", n)), " ")).unwrap();
+
+        if matches > 0 {
+            points += 1 << (matches - 1);
+        }
+        for next in id + 1..=id + matches {
+            copies[next] += copies[id];
+        }
+    }
+
+    Generated::new(input).answer(1, points).answer(2, copies.iter().sum::<usize>())
+}
+
+// 5: every category shuffles whole slices of 0..2^32 around, and leaves some of them in place
+fn seed_fertilizer(rng: &mut Rng, size: usize) -> Generated {
+    const CATEGORIES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+    const DOMAIN: i64 = 1 << 32;
+
+    let seeds: Vec<String> = (0..(size / 5).max(1))
+        .map(|_| format!("{} {}", rng.between(0, DOMAIN - 1), rng.between(1, DOMAIN / 16)))
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for names in CATEGORIES.windows(2) {
+        write!(input, "\n{}-to-{} map:\n", names[0], names[1]).unwrap();
+
+        let mut cuts: Vec<i64> = (0..(size / 3).max(1)).map(|_| rng.between(1, DOMAIN - 1)).collect();
+        cuts.extend([0, DOMAIN]);
+        cuts.sort();
+        cuts.dedup();
+        let mut slices: Vec<(i64, i64)> = cuts.windows(2).map(|c| (c[0], c[1] - c[0])).collect();
+
+        // laying the shuffled slices one after the other maps the whole domain onto itself
+        rng.shuffle(&mut slices);
+        let mut destination = 0;
+        for (source, length) in slices {
+            if rng.chance(0.8) {
+                writeln!(input, "{} {} {}", destination, source, length).unwrap();
+            }
+            destination += length;
+        }
+    }
+
+    Generated::new(input)
+}
+
+// 6: up to 4 races of less than 100 ms, whose joined up times and records can still be beaten
+fn wait_for_it(rng: &mut Rng, size: usize) -> Generated {
+    loop {
+        let mut races = vec![];
+        for _ in 0..(size / 25).clamp(1, 4) {
+            let time = rng.between(7, 99);
+            let hold = rng.between(1, (time - 2) / 2);
+            races.push((time, hold * (time - hold)));
+        }
+
+        let time: i64 = join(races.iter().map(|r| r.0), "").parse().unwrap();
+        let distance: i64 = join(races.iter().map(|r| r.1), "").parse().unwrap();
+        if distance < (time / 2) * (time - time / 2) {
+            let times = join(races.iter().map(|r| format!("{:>7}", r.0)), "");
+            let distances = join(races.iter().map(|r| format!("{:>7}", r.1)), "");
+
+            return Generated::new(format!("Time:    {}\nDistance:{}\n", times, distances));
+        }
+    }
+}
+
+// 7: different hands, made from a few kinds of cards so there are plenty of pairs, sets and jokers
+fn camel_cards(rng: &mut Rng, size: usize) -> Generated {
+    const CARDS: &[u8] = b"23456789TJQKA";
+
+    let mut hands = HashSet::new();
+    let mut input = String::new();
+    while hands.len() < size.min(CARDS.len().pow(5)) {
+        let kinds = rng.between(1, 5) as usize;
+        let pool = word(rng, kinds, CARDS);
+        let hand = word(rng, 5, pool.as_bytes());
+
+        if hands.insert(hand.clone()) {
+            writeln!(input, "{} {}", hand, rng.between(1, 1000)).unwrap();
+        }
+    }
+
+    Generated::new(input)
+}
+
+/**
+ * 8: every ghost walks a loop of a whole number of passes over the instructions, and is only on its **Z node
+ * at the end of the loop, so they meet at the lcm of the loop lengths. AAA is the first ghost.
+ * The side of a node that the walk does not take leads anywhere.
+ */
+fn haunted_wasteland(rng: &mut Rng, size: usize) -> Generated {
+    // no A or Z, those end the start and end nodes
+    const INNER: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY";
+
+    let path_len = rng.between(2, 12) as usize;
+    let path: Vec<char> = (0..path_len).map(|_| if rng.chance(0.5) { 'L' } else { 'R' }).collect();
+
+    let ghosts = (size / 20).clamp(1, 6);
+    let steps: Vec<usize> = (0..ghosts).map(|_| path_len * rng.between(2, (7 + size as i64 / 10).min(150)) as usize).collect();
+
+    let prefixes: Vec<String> = ["AA".to_string()].into_iter().chain(unique_words(rng, ghosts - 1, 2, INNER, &[])).collect();
+    let mut inner = unique_words(rng, steps.iter().map(|s| s - 1).sum(), 3, INNER, &[]).into_iter();
+
+    // the nodes of each ghost in walking order, from **A to **Z
+    let walks: Vec<Vec<String>> = steps.iter().zip(&prefixes)
+        .map(|(n, prefix)| {
+            let start = format!("{}A", prefix);
+            let end = if prefix == "AA" { "ZZZ".to_string() } else { format!("{}Z", prefix) };
+            [start].into_iter().chain(inner.by_ref().take(n - 1)).chain([end]).collect()
+        })
+        .collect();
+    let names: Vec<&String> = walks.iter().flatten().collect();
+
+    let mut entries = vec![];
+    for walk in &walks {
+        let n = walk.len() - 1;
+        // the end node goes on like the start one, as the walk is back at the first instruction
+        for t in 0..=n {
+            let next = if t < n { &walk[t + 1] } else { &walk[1] };
+            let other = *rng.pick(&names);
+            let (left, right) = if path[t % path_len] == 'L' { (next, other) } else { (other, next) };
+            entries.push(format!("{} = ({}, {})", walk[t], left, right));
+        }
+    }
+    rng.shuffle(&mut entries);
+
+    let input = format!("{}\n\n{}\n", join(&path, ""), entries.join("\n"));
+    let meet = math::lcm_all(steps.iter().map(|s| *s as u64)).expect("the ghosts meet too late");
+
+    Generated::new(input).answer(1, steps[0]).answer(2, meet)
+}
+
+// 9: values of polynomials up to degree 5, so the answers are the polynomials one step past each end
+fn mirage_maintenance(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let (mut next, mut previous) = (0, 0);
+
+    for _ in 0..size {
+        let len = rng.between(6, 21) as usize;
+        // the differences have to get down to a row of zeros within the values
+        let degree = rng.between(0, 5.min(len as i64 - 2)) as usize;
+        let differences: Vec<i64> = (0..=degree).map(|_| rng.between(-6, 6)).collect();
+
+        // one more value before and after the ones in the input
+        let values = newton_values(&differences, len + 2);
+        writeln!(input, "{}", join(&values[1..=len], " ")).unwrap();
+
+        previous += values[0];
+        next += values[len + 1];
+    }
+
+    Generated::new(input).answer(1, next).answer(2, previous)
+}
+
+// 10: the outline of a random shape is the loop, with pipes that lead nowhere all around it (but not into S)
+fn pipe_maze(rng: &mut Rng, size: usize) -> Generated {
+    const PIPES: [(char, [Direction; 2]); 6] = [
+        ('|', [Direction::Up, Direction::Down]),
+        ('-', [Direction::Left, Direction::Right]),
+        ('L', [Direction::Up, Direction::Right]),
+        ('J', [Direction::Up, Direction::Left]),
+        ('7', [Direction::Down, Direction::Left]),
+        ('F', [Direction::Down, Direction::Right])
+    ];
+    let opens = |pipe: char, dir: Direction| PIPES.iter().any(|(p, openings)| *p == pipe && openings.contains(&dir));
+
+    // the loop runs along the corners of the shape cells, which are the maze tiles
+    let side = size.max(3);
+    let shape = polyomino(rng, side - 1, side - 1);
+    let pipe_loop = outline(&shape);
+    let on_loop: HashSet<(usize, usize)> = pipe_loop.iter().map(|(pos, _)| *pos).collect();
+
+    let mut maze = Grid::from_fn(side, side, |_| if rng.chance(0.5) { rng.pick(&PIPES).0 } else { '.' });
+    for (i, (pos, out)) in pipe_loop.iter().enumerate() {
+        let into = pipe_loop[(i + pipe_loop.len() - 1) % pipe_loop.len()].1.opposite();
+        maze[*pos] = PIPES.iter().find(|(_, openings)| openings.contains(out) && openings.contains(&into)).unwrap().0;
+    }
+
+    let start = rng.pick(&pipe_loop).0;
+    maze[start] = 'S';
+    for dir in Direction::ALL {
+        if let Some(next) = maze.step(start, dir).filter(|next| !on_loop.contains(next)) {
+            if opens(maze[next], dir.opposite()) {
+                maze[next] = '.';
+            }
+        }
+    }
+
+    // Pick's theorem: the shape area is the enclosed tiles plus half the loop minus 1
+    let area = shape.find_all(&true).count();
+    let length = pipe_loop.len();
+
+    Generated::new(maze.to_string()).answer(1, length / 2).answer(2, area + 1 - length / 2)
+}
+
+// 11: a few galaxies, and some rows and columns kept empty
+fn cosmic_expansion(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(1);
+    let empty_rows: Vec<bool> = (0..side).map(|_| rng.chance(0.15)).collect();
+    let empty_columns: Vec<bool> = (0..side).map(|_| rng.chance(0.15)).collect();
+
+    let image = Grid::from_fn(side, side, |(x, y)| {
+        if !empty_rows[y] && !empty_columns[x] && rng.chance(0.03) { '#' } else { '.' }
+    });
+
+    Generated::new(image.to_string())
+}
+
+// 12: the groups of a random row of springs, some of which become unknown
+fn hot_springs(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut springs: Vec<char>;
+        let groups = loop {
+            let len = rng.between(1, 20) as usize;
+            springs = (0..len).map(|_| if rng.chance(0.45) { '#' } else { '.' }).collect();
+            let groups: Vec<usize> = springs.split(|ch| *ch == '.').map(|run| run.len()).filter(|n| *n > 0).collect();
+            if !groups.is_empty() {
+                break groups;
+            }
+        };
+
+        // at most 9 unknowns, so even unfolded (49 of them) the counts add up within a u64
+        let mut positions: Vec<usize> = (0..springs.len()).collect();
+        rng.shuffle(&mut positions);
+        let unknowns = rng.between(0, springs.len().min(9) as i64) as usize;
+        for pos in &positions[..unknowns] {
+            springs[*pos] = '?';
+        }
+
+        writeln!(input, "{} {}", springs.iter().collect::<String>(), join(groups, ",")).unwrap();
+    }
+
+    Generated::new(input)
+}
+
+// the mismatched cells between the rows reflected over the line before `line`
+fn mirror_diffs(rows: &[Vec<bool>], line: usize) -> usize {
+    (0..line.min(rows.len() - line))
+        .map(|i| rows[line - 1 - i].iter().zip(&rows[line + i]).filter(|(a, b)| a != b).count())
+        .sum()
+}
+
+fn transpose(rows: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    (0..rows[0].len()).map(|x| rows.iter().map(|row| row[x]).collect()).collect()
+}
+
+/**
+ * 13: patterns mirrored over a column and over a row, with a smudge that only breaks the row mirror.
+ * Patterns where any other line happens to reflect are thrown away. Half of them are transposed.
+ */
+fn point_of_incidence(rng: &mut Rng, size: usize) -> Generated {
+    let mut patterns = vec![];
+    let (mut before, mut after) = (0, 0);
+
+    while patterns.len() < size.max(1) {
+        let (width, height) = (rng.between(5, 17) as usize, rng.between(5, 17) as usize);
+        let (column, row) = (rng.between(1, width as i64 - 1) as usize, rng.between(1, height as i64 - 1) as usize);
+        // the smudge has to go in a column the column mirror does not reach
+        let reach = column.min(width - column);
+        let free_columns: Vec<usize> = (0..width).filter(|x| *x + reach < column || *x >= column + reach).collect();
+        if free_columns.is_empty() {
+            continue;
+        }
+
+        let mut rows: Vec<Vec<bool>> = (0..height).map(|_| (0..width).map(|_| rng.chance(0.5)).collect()).collect();
+        for cells in rows.iter_mut() {
+            for i in 0..reach {
+                cells[column + i] = cells[column - 1 - i];
+            }
+        }
+        let row_reach = row.min(height - row);
+        for i in 0..row_reach {
+            rows[row + i] = rows[row - 1 - i].clone();
+        }
+
+        let y = rng.between((row - row_reach) as i64, (row + row_reach - 1) as i64) as usize;
+        let x = *rng.pick(&free_columns);
+        rows[y][x] = !rows[y][x];
+
+        let columns = transpose(&rows);
+        let diffs: Vec<usize> = (1..height).map(|l| mirror_diffs(&rows, l))
+            .chain((1..width).map(|l| mirror_diffs(&columns, l)))
+            .collect();
+        if diffs.iter().filter(|d| **d == 0).count() != 1 || diffs.iter().filter(|d| **d == 1).count() != 1 {
+            continue;
+        }
+
+        let pattern = if rng.chance(0.5) {
+            before += 100 * column;
+            after += row;
+            columns
+        } else {
+            before += column;
+            after += 100 * row;
+            rows
+        };
+        patterns.push(join(pattern.iter().map(|cells| cells.iter().map(|c| if *c { '#' } else { '.' }).collect::<String>()), "\n"));
+    }
+
+    Generated::new(join(patterns, "\n\n") + "\n").answer(1, before).answer(2, after)
+}
+
+// 14: rounded and cube rocks anywhere
+fn parabolic_reflector_dish(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(1);
+    let dish = Grid::from_fn(side, side, |_| match rng.below(20) {
+        0..=3 => 'O',
+        4..=6 => '#',
+        _ => '.'
+    });
+
+    Generated::new(dish.to_string())
+}
+
+// 15: steps on a small set of labels, so lenses are often replaced and removed (and labels prefix one another)
+fn lens_library(rng: &mut Rng, size: usize) -> Generated {
+    let labels: Vec<String> = (0..(size / 4).max(1))
+        .map(|_| {
+            let len = rng.between(1, 6) as usize;
+            word(rng, len, LOWERCASE)
+        })
+        .collect();
+
+    let steps: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let label = rng.pick(&labels);
+            if rng.chance(0.4) { format!("{}-", label) } else { format!("{}={}", label, rng.between(1, 9)) }
+        })
+        .collect();
+
+    Generated::new(steps.join(",") + "\n")
+}
+
+// 16: mostly empty space with some mirrors and splitters
+fn the_floor_will_be_lava(rng: &mut Rng, size: usize) -> Generated {
+    const DEVICES: [char; 4] = ['/', '\\', '|', '-'];
+
+    let side = size.max(1);
+    let contraption = Grid::from_fn(side, side, |_| if rng.chance(0.12) { *rng.pick(&DEVICES) } else { '.' });
+
+    Generated::new(contraption.to_string())
+}
+
+// 17: any heat loss, at least 5 blocks wide so the ultra crucible can get to the end
+fn clumsy_crucible(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(5);
+    let map = Grid::from_fn(side, side, |_| char::from(b'0' + rng.between(1, 9) as u8));
+
+    Generated::new(map.to_string())
+}
+
+/**
+ * The trench around `shape` when each column and row of cells is a random width up to `max_gap`,
+ * as (direction, steps) moves, along with the volume of the lagoon it digs out.
+ */
+fn dig_plan(rng: &mut Rng, shape: &Grid<bool>, max_gap: i64) -> (Vec<(Direction, i64)>, i64) {
+    let widths: Vec<i64> = (0..shape.width()).map(|_| rng.between(1, max_gap)).collect();
+    let heights: Vec<i64> = (0..shape.height()).map(|_| rng.between(1, max_gap)).collect();
+
+    let mut moves: Vec<(Direction, i64)> = vec![];
+    for ((x, y), dir) in outline(shape) {
+        let steps = match dir {
+            Direction::Right => widths[x],
+            Direction::Left => widths[x - 1],
+            Direction::Down => heights[y],
+            Direction::Up => heights[y - 1]
+        };
+        match moves.last_mut() {
+            Some((last, n)) if *last == dir => *n += steps,
+            _ => moves.push((dir, steps))
+        }
+    }
+
+    // Pick's theorem: the area is the inside plus half the trench minus 1
+    let area: i64 = shape.iter().filter(|(_, filled)| **filled).map(|((x, y), _)| widths[x] * heights[y]).sum();
+    let trench: i64 = moves.iter().map(|(_, steps)| steps).sum();
+
+    (moves, area + trench / 2 + 1)
+}
+
+// 18: the plan and the colors dig the outline of the same shape (or its transpose), at very different scales
+fn lavaduct_lagoon(rng: &mut Rng, size: usize) -> Generated {
+    let cells = (size / 5).max(1);
+    let shape = polyomino(rng, cells, cells);
+    let color_shape = if rng.chance(0.5) { shape.transposed() } else { shape.clone() };
+
+    let (plan, volume) = dig_plan(rng, &shape, 10);
+    // a color move has 5 hex digits of steps
+    let (color_plan, color_volume) = dig_plan(rng, &color_shape, 0xfffff / cells as i64);
+
+    let letter = |dir| match dir {
+        Direction::Right => 'R',
+        Direction::Down => 'D',
+        Direction::Left => 'L',
+        Direction::Up => 'U'
+    };
+    let digit = |dir| match dir {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3
+    };
+
+    let mut input = String::new();
+    for ((dir, steps), (color_dir, color_steps)) in plan.into_iter().zip(color_plan) {
+        writeln!(input, "{} {} (#{:05x}{})", letter(dir), steps, color_steps, digit(color_dir)).unwrap();
+    }
+
+    Generated::new(input).answer(1, volume).answer(2, color_volume)
+}
+
+// 19: a tree of workflows from "in", every one of them used once, and random parts
+fn aplenty(rng: &mut Rng, size: usize) -> Generated {
+    let names = unique_words(rng, (size / 2).clamp(1, 10000), 3, LOWERCASE, &[]);
+    let mut unused = names.iter();
+
+    let mut workflows = vec![];
+    let mut queue = VecDeque::from(["in".to_string()]);
+    while let Some(name) = queue.pop_front() {
+        let mut targets = vec![];
+        for _ in 0..rng.between(2, 4) {
+            match unused.next().filter(|_| rng.chance(0.7)) {
+                Some(next) => {
+                    queue.push_back(next.clone());
+                    targets.push(next.clone());
+                },
+                None => targets.push(if rng.chance(0.5) { "A" } else { "R" }.to_string())
+            }
+        }
+
+        let fallback = targets.pop().unwrap();
+        let rules: Vec<String> = targets.iter()
+            .map(|target| format!("{}{}{}:{}", *rng.pick(b"xmas") as char, *rng.pick(b"<>") as char, rng.between(1, 4000), target))
+            .collect();
+        workflows.push(format!("{}{{{},{}}}", name, rules.join(","), fallback));
+    }
+    rng.shuffle(&mut workflows);
+
+    let parts: Vec<String> = (0..size.max(1))
+        .map(|_| format!("{{x={},m={},a={},s={}}}", rng.between(1, 4000), rng.between(1, 4000), rng.between(1, 4000), rng.between(1, 4000)))
+        .collect();
+
+    Generated::new(format!("{}\n\n{}\n", workflows.join("\n"), parts.join("\n")))
+}
+
+fn is_prime(n: u64) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+}
+
+/**
+ * 20: like the puzzle, rx is fed by a conjunction of inverters, each behind a 12 bit counter made of a chain of
+ * flip-flops and a conjunction. The conjunction reads the bits of a prime and resets the counter when it gets there,
+ * so rx gets a low pulse after the product of the primes.
+ */
+fn pulse_propagation(rng: &mut Rng, size: usize) -> Generated {
+    const BITS: usize = 12;
+
+    let counters = (size / 25).clamp(1, 5);
+    let mut primes: Vec<u64> = vec![];
+    while primes.len() < counters {
+        let p = rng.between(1 << (BITS - 1), (1 << BITS) - 1) as u64;
+        if is_prime(p) && !primes.contains(&p) {
+            primes.push(p);
+        }
+    }
+
+    let mut names = unique_words(rng, counters * (BITS + 2) + 1, 2, LOWERCASE, &["rx"]).into_iter();
+    let output = names.next().unwrap();
+
+    let mut modules = vec![format!("&{} -> rx", output)];
+    let mut firsts = vec![];
+    for p in &primes {
+        let flip_flops: Vec<String> = names.by_ref().take(BITS).collect();
+        let (conjunction, inverter) = (names.next().unwrap(), names.next().unwrap());
+
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut targets: Vec<&String> = flip_flops.get(bit + 1).into_iter().collect();
+            if p & (1 << bit) != 0 {
+                targets.push(&conjunction);
+            }
+            rng.shuffle(&mut targets);
+            modules.push(format!("%{} -> {}", flip_flop, join(targets, ", ")));
+        }
+
+        // the first flip-flop and the zero bits, which carries all the way over and leaves the counter at 0
+        let mut targets: Vec<&String> = flip_flops.iter().enumerate()
+            .filter(|(bit, _)| *bit == 0 || p & (1 << bit) == 0)
+            .map(|(_, name)| name)
+            .chain([&inverter])
+            .collect();
+        rng.shuffle(&mut targets);
+        modules.push(format!("&{} -> {}", conjunction, join(targets, ", ")));
+        modules.push(format!("&{} -> {}", inverter, output));
+
+        firsts.push(flip_flops[0].clone());
+    }
+    modules.push(format!("broadcaster -> {}", firsts.join(", ")));
+    rng.shuffle(&mut modules);
+
+    Generated::new(modules.join("\n") + "\n").answer(2, primes.iter().product::<u64>())
+}
+
+/**
+ * 21: `size` plots wide and high, made odd so the start is in the middle. The middle row and column and the edges
+ * are kept free of rocks as in the puzzle, which part 2 relies on to grow steadily. Only the puzzle's 131 (and 3 and
+ * 393) make the 26501365 steps end on the edge of a garden copy, as the formula the day is tested against needs.
+ */
+fn step_counter(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(5) | 1;
+    let middle = side / 2;
+
+    let mut garden = Grid::from_fn(side, side, |(x, y)| {
+        let free = x == 0 || y == 0 || x == side - 1 || y == side - 1 || x == middle || y == middle;
+        if !free && rng.chance(0.12) { '#' } else { '.' }
+    });
+    garden[(middle, middle)] = 'S';
+
+    Generated::new(garden.to_string())
+}
+
+// 22: bricks up to 4 cubes long in a 10x10 column, that do not overlap before falling
+fn sand_slabs(rng: &mut Rng, size: usize) -> Generated {
+    let height = (size as i64 * 3 / 10).max(5);
+
+    let mut occupied = HashSet::new();
+    let mut input = String::new();
+    let mut bricks = 0;
+    for _ in 0..size * 100 {
+        if bricks >= size {
+            break;
+        }
+
+        let begin = (rng.between(0, 9), rng.between(0, 9), rng.between(1, height));
+        let len = rng.between(0, 3);
+        let end = match rng.below(3) {
+            0 => (begin.0 + len, begin.1, begin.2),
+            1 => (begin.0, begin.1 + len, begin.2),
+            _ => (begin.0, begin.1, begin.2 + len)
+        };
+        if end.0 > 9 || end.1 > 9 || end.2 > height {
+            continue;
+        }
+
+        let cubes: Vec<(i64, i64, i64)> = (begin.0..=end.0)
+            .flat_map(|x| (begin.1..=end.1).flat_map(move |y| (begin.2..=end.2).map(move |z| (x, y, z))))
+            .collect();
+        if cubes.iter().all(|cube| !occupied.contains(cube)) {
+            occupied.extend(cubes);
+            writeln!(input, "{},{},{}~{},{},{}", begin.0, begin.1, begin.2, end.0, end.1, end.2).unwrap();
+            bricks += 1;
+        }
+    }
+
+    Generated::new(input)
+}
+
+/**
+ * 23: like the puzzle, the junctions are on a square lattice, every one linked to the next one right and down,
+ * with slopes that only let the hike go right and down next to them. The trails between junctions can make a detour,
+ * the ones along a row into the lower right quarter of the block below them, the ones along a column into the
+ * upper left of the block on their right, so they never touch.
+ */
+fn a_long_walk(rng: &mut Rng, size: usize) -> Generated {
+    let junctions = (size / 20).clamp(2, 6);
+
+    let mut lattice = |first: i64| {
+        let mut lines = vec![rng.between(first, first + 3) as usize];
+        for _ in 1..junctions {
+            lines.push(lines.last().unwrap() + rng.between(10, 16) as usize);
+        }
+        lines
+    };
+    let (xs, ys) = (lattice(1), lattice(2));
+
+    let width = xs.last().unwrap() + rng.between(2, 4) as usize;
+    let height = ys.last().unwrap() + rng.between(2, 4) as usize;
+    let mut map = Grid::from_fn(width, height, |_| '#');
+
+    let trail = |map: &mut Grid<char>, from: (usize, usize), to: (usize, usize)| {
+        for x in from.0.min(to.0)..=from.0.max(to.0) {
+            for y in from.1.min(to.1)..=from.1.max(to.1) {
+                map[(x, y)] = '.';
+            }
+        }
+    };
+
+    trail(&mut map, (xs[0], 0), (xs[0], ys[0]));
+    trail(&mut map, (xs[junctions - 1], ys[junctions - 1]), (xs[junctions - 1], height - 1));
+
+    for (i, &x) in xs.iter().enumerate() {
+        for (j, &y) in ys.iter().enumerate() {
+            if let Some(&next) = xs.get(i + 1) {
+                let half = (next - x) / 2;
+                match ys.get(j + 1).filter(|_| rng.chance(0.5)) {
+                    Some(&below) => {
+                        let a = rng.between((x + half + 1) as i64, (next - 4) as i64) as usize;
+                        let b = rng.between((a + 2) as i64, (next - 2) as i64) as usize;
+                        let depth = rng.between(1, ((below - y) / 2 - 1) as i64) as usize;
+                        trail(&mut map, (x, y), (a, y));
+                        trail(&mut map, (a, y), (a, y + depth));
+                        trail(&mut map, (a, y + depth), (b, y + depth));
+                        trail(&mut map, (b, y + depth), (b, y));
+                        trail(&mut map, (b, y), (next, y));
+                    },
+                    None => trail(&mut map, (x, y), (next, y))
+                }
+                map[(x + 1, y)] = '>';
+                map[(next - 1, y)] = '>';
+            }
+
+            if let Some(&next) = ys.get(j + 1) {
+                let half = (next - y) / 2;
+                match xs.get(i + 1).filter(|_| rng.chance(0.5)) {
+                    Some(&right) => {
+                        let a = rng.between((y + half + 1) as i64, (next - 4) as i64) as usize;
+                        let b = rng.between((a + 2) as i64, (next - 2) as i64) as usize;
+                        let depth = rng.between(1, ((right - x) / 2 - 1) as i64) as usize;
+                        trail(&mut map, (x, y), (x, a));
+                        trail(&mut map, (x, a), (x + depth, a));
+                        trail(&mut map, (x + depth, a), (x + depth, b));
+                        trail(&mut map, (x + depth, b), (x, b));
+                        trail(&mut map, (x, b), (x, next));
+                    },
+                    None => trail(&mut map, (x, y), (x, next))
+                }
+                map[(x, y + 1)] = 'v';
+                map[(x, next - 1)] = 'v';
+            }
+        }
+    }
+
+    Generated::new(map.to_string())
+}
+
+/**
+ * 24: a rock thrown from around the test area hits every hailstone at a different time,
+ * so each hailstone starts where the rock will be then, minus its own way there.
+ */
+fn never_tell_me_the_odds(rng: &mut Rng, size: usize) -> Generated {
+    let rock: Vec<i64> = (0..3).map(|_| rng.between(200_000_000_000_000, 400_000_000_000_000)).collect();
+    let throw: Vec<i64> = (0..3).map(|_| rng.between(-250, 250)).collect();
+
+    let mut times = HashSet::new();
+    let mut input = String::new();
+    while times.len() < size.max(3) {
+        let time = rng.between(1_000_000_000, 300_000_000_000);
+        let velocity: Vec<i64> = (0..3).map(|_| rng.between(-250, 250)).collect();
+        if velocity.contains(&0) || !times.insert(time) {
+            continue;
+        }
+
+        let position: Vec<i64> = (0..3).map(|i| rock[i] + time * (throw[i] - velocity[i])).collect();
+        writeln!(input, "{} @ {}", join(position, ", "), join(velocity, ", ")).unwrap();
+    }
+
+    Generated::new(input).answer(2, rock.iter().sum::<i64>())
+}
+
+/**
+ * 25: two groups of components joined by 3 wires. Each group is two different Hamiltonian cycles plus random wires,
+ * so cutting it takes at least 4 wires and the 3 planted ones are the only cut. The groups are not balanced.
+ */
+fn snowverload(rng: &mut Rng, size: usize) -> Generated {
+    let count = size.clamp(14, 10000);
+    let first = rng.between(7, count as i64 - 7) as usize;
+    let names = unique_words(rng, count, 3, LOWERCASE, &[]);
+
+    let mut wires: HashSet<(usize, usize)> = HashSet::new();
+    let mut link = |a: usize, b: usize| wires.insert((a.min(b), a.max(b)));
+
+    for (start, end) in [(0, first), (first, count)] {
+        let mut group: Vec<usize> = (start..end).collect();
+        rng.shuffle(&mut group);
+
+        // a step coprime with the group size walks a cycle through all of it, one that is not the first cycle
+        let n = group.len();
+        let step = (2..=n / 2).find(|s| math::gcd(*s, n) == 1).unwrap();
+        for i in 0..n {
+            link(group[i], group[(i + 1) % n]);
+            link(group[i], group[(i + step) % n]);
+        }
+        for _ in 0..n / 2 {
+            let (a, b) = (*rng.pick(&group), *rng.pick(&group));
+            if a != b {
+                link(a, b);
+            }
+        }
+    }
+
+    let mut cut = 0;
+    while cut < 3 {
+        if link(rng.below(first), first + rng.below(count - first)) {
+            cut += 1;
+        }
+    }
+
+    // every wire is listed once, on either end
+    let mut links: Vec<Vec<usize>> = vec![vec![]; count];
+    let mut wires: Vec<(usize, usize)> = wires.into_iter().collect();
+    wires.sort();
+    for (a, b) in wires {
+        if rng.chance(0.5) { links[a].push(b) } else { links[b].push(a) }
+    }
+
+    let mut lines: Vec<String> = links.iter().enumerate()
+        .filter(|(_, others)| !others.is_empty())
+        .map(|(a, others)| format!("{}: {}", names[a], join(others.iter().map(|b| &names[*b]), " ")))
+        .collect();
+    rng.shuffle(&mut lines);
+
+    Generated::new(lines.join("\n") + "\n").answer(1, first * (count - first))
+}
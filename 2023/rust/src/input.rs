@@ -0,0 +1,354 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::str::FromStr;
+
+/**
+ * A problem in the puzzle input, located by 1-based line and column.
+ * Parsers working on a single line leave the line at 0 and `parse_lines` fills it in.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self { line, column, message: message.into() }
+    }
+
+    // an error about `part`, which must be a slice of `text`, so the column can be worked out
+    pub fn at(text: &str, part: &str, message: impl Into<String>) -> Self {
+        Self::new(0, column_of(text, part), message)
+    }
+
+    // an error about something missing at the end of `text`
+    pub fn missing(text: &str, what: &str) -> Self {
+        Self::new(0, text.len() + 1, format!("missing {}", what))
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    // an error from a block of lines, moved down by the `lines` before it
+    pub fn shifted(mut self, lines: usize) -> Self {
+        self.line = self.line.max(1) + lines;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+// The offending line of `text` with a caret under the column, if the error has a line
+pub fn excerpt(text: &str, error: &ParseError) -> Option<String> {
+    let line = text.lines().nth(error.line.checked_sub(1)?)?;
+    let pad = " ".repeat(error.column.saturating_sub(1));
+
+    Some(format!("  {}\n  {}^", line, pad))
+}
+
+fn column_of(text: &str, part: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let pos = part.as_ptr() as usize;
+
+    if pos >= start && pos <= start + text.len() {
+        pos - start + 1
+    } else {
+        1
+    }
+}
+
+// Parses `part` (a slice of `text`) into a value, `what` names it in the error
+pub fn value<T: FromStr>(text: &str, part: &str, what: &str) -> ParseResult<T> {
+    let part = part.trim();
+    part.parse().map_err(|_| ParseError::at(text, part, format!("invalid {} '{}'", what, part)))
+}
+
+// The next piece of a split `text`, which must be there
+pub fn next<'a>(text: &str, it: &mut impl Iterator<Item = &'a str>, what: &str) -> ParseResult<&'a str> {
+    it.next().ok_or_else(|| ParseError::missing(text, what))
+}
+
+// Parses every line on its own, errors get the line number
+pub fn parse_lines<'a, T>(input: &'a str, parse: impl Fn(&'a str) -> ParseResult<T>) -> ParseResult<Vec<T>> {
+    input.lines().enumerate()
+        .map(|(i, text)| parse(text).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+// The whitespace separated words of `part` (a slice of `text`), each parsed as a `what`
+pub fn list<T: FromStr>(text: &str, part: &str, what: &str) -> ParseResult<Vec<T>> {
+    part.split_whitespace().map(|item| value(text, item, what)).collect()
+}
+
+// The `sep` separated items of `part`, each parsed as a `what`
+pub fn separated<T: FromStr>(text: &str, part: &str, sep: char, what: &str) -> ParseResult<Vec<T>> {
+    part.split(sep).map(|item| value(text, item, what)).collect()
+}
+
+// Every integer in `part` with its sign, whatever is around them
+pub fn ints<T: FromStr>(text: &str, part: &str, what: &str) -> ParseResult<Vec<T>> {
+    let bytes = part.as_bytes();
+    let mut values = vec![];
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+        if bytes[i].is_ascii_digit() || negative {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            values.push(value(text, &part[start..i], what)?);
+        } else {
+            i += 1;
+        }
+    }
+
+    Ok(values)
+}
+
+// The `items` found in `part`, which must be exactly N `what`
+pub fn exactly<T, const N: usize>(text: &str, part: &str, items: Vec<T>, what: &str) -> ParseResult<[T; N]> {
+    let found = items.len();
+    items.try_into().map_err(|_| ParseError::at(text, part, format!("expected {} {}, found {}", N, what, found)))
+}
+
+// The N whitespace separated words of `part`
+pub fn fields<'a, const N: usize>(text: &str, part: &'a str, what: &str) -> ParseResult<[&'a str; N]> {
+    exactly(text, part, part.split_whitespace().collect(), what)
+}
+
+// `part` split at the first `sep` into two trimmed sides, `what` names the right one
+pub fn split_once<'a>(text: &str, part: &'a str, sep: &str, what: &str) -> ParseResult<(&'a str, &'a str)> {
+    part.split_once(sep)
+        .map(|(left, right)| (left.trim(), right.trim()))
+        .ok_or_else(|| ParseError::new(0, column_of(text, part) + part.len(), format!("missing '{}' and {}", sep, what)))
+}
+
+// The rest of a `label: value` style `part` after the `label`
+pub fn after<'a>(text: &str, part: &'a str, label: &str) -> ParseResult<&'a str> {
+    let part = part.trim_start();
+    part.strip_prefix(label).map(str::trim).ok_or_else(|| ParseError::at(text, part, format!("expected '{}'", label)))
+}
+
+// What is between `open` and `close` in `part`, e.g. the inside of "(a, b)"
+pub fn enclosed<'a>(text: &str, part: &'a str, open: char, close: char) -> ParseResult<&'a str> {
+    let part = part.trim();
+    part.strip_prefix(open).and_then(|p| p.strip_suffix(close)).map(str::trim)
+        .ok_or_else(|| ParseError::at(text, part, format!("expected '{}...{}'", open, close)))
+}
+
+// A `name{...}` record as its name, which can be empty, and what is inside the braces
+pub fn record<'a>(text: &str, part: &'a str) -> ParseResult<(&'a str, &'a str)> {
+    let part = part.trim();
+    let open = part.find('{').ok_or_else(|| ParseError::at(text, part, "expected '<name>{...}'"))?;
+
+    Ok((part[..open].trim(), enclosed(text, &part[open..], '{', '}')?))
+}
+
+// The blank line separated blocks of `input`, each with the number of lines before it
+pub fn blocks(input: &str) -> Vec<(usize, &str)> {
+    let mut blocks = vec![];
+    // (first line index, start, end) of the block being read
+    let mut current: Option<(usize, usize, usize)> = None;
+
+    for (i, text) in input.lines().enumerate() {
+        let start = column_of(input, text) - 1;
+        if text.trim().is_empty() {
+            if let Some((first, from, to)) = current.take() {
+                blocks.push((first, &input[from..to]));
+            }
+        } else {
+            let (first, from) = current.map_or((i, start), |(first, from, _)| (first, from));
+            current = Some((first, from, start + text.len()));
+        }
+    }
+    if let Some((first, from, to)) = current {
+        blocks.push((first, &input[from..to]));
+    }
+
+    blocks
+}
+
+// Parses every block on its own, errors get the line number in the whole input
+pub fn parse_blocks<T>(input: &str, parse: impl Fn(&str) -> ParseResult<T>) -> ParseResult<Vec<T>> {
+    blocks(input).into_iter()
+        .map(|(lines, block)| parse(block).map_err(|e| e.shifted(lines)))
+        .collect()
+}
+
+// The lines of a rectangular grid where every character must be `allowed`
+pub fn grid_lines(input: &str, allowed: impl Fn(char) -> bool) -> ParseResult<Vec<&str>> {
+    let lines: Vec<&str> = input.lines().collect();
+
+    let width = lines.first().map_or(0, |l| l.len());
+    if width == 0 {
+        return Err(ParseError::new(1, 1, "empty grid"));
+    }
+
+    for (i, text) in lines.iter().enumerate() {
+        if let Some((col, ch)) = text.chars().enumerate().find(|(_, ch)| !allowed(*ch)) {
+            return Err(ParseError::new(i + 1, col + 1, format!("unexpected '{}'", ch)));
+        }
+        if text.len() != width {
+            return Err(ParseError::new(i + 1, text.len().min(width) + 1,
+                format!("expected {} columns, found {}", width, text.len())));
+        }
+    }
+
+    Ok(lines)
+}
+
+/**
+ * Where the puzzle input failed, either reading it or parsing it.
+ * `file` is the input path, or "stdin".
+ */
+#[derive(Debug)]
+pub enum InputError {
+    Io { file: String, source: io::Error },
+    Parse { file: String, error: ParseError }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io { file, source } => write!(f, "{}: {}", file, source),
+            InputError::Parse { file, error } => write!(f, "{}:{}", file, error)
+        }
+    }
+}
+
+// Reads the whole puzzle input, no file means stdin
+pub fn read_input(input_file: Option<&str>) -> Result<String, InputError> {
+    let mut text = String::new();
+
+    let result = match input_file {
+        Some(path) => File::open(path).and_then(|mut file| file.read_to_string(&mut text)),
+        None => io::stdin().read_to_string(&mut text)
+    };
+
+    result
+        .map(|_| text)
+        .map_err(|source| InputError::Io { file: input_file.unwrap_or("stdin").to_string(), source })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // "line:column: message", as it is printed
+    fn error<T>(result: ParseResult<T>) -> String {
+        match result {
+            Ok(_) => panic!("expected a parse error"),
+            Err(e) => e.to_string()
+        }
+    }
+
+    #[test]
+    fn values_and_their_columns() {
+        let text = "Time:  7 x 300";
+
+        assert_eq!(value::<u32>(text, &text[6..8], "time"), Ok(7));
+        assert_eq!(error(value::<u32>(text, &text[9..10], "time")), "0:10: invalid time 'x'");
+        // the column is where the trimmed value starts
+        assert_eq!(error(value::<u8>(text, &text[10..], "time")), "0:12: invalid time '300'");
+        // and the first one when the part is not a slice of the text
+        assert_eq!(error(value::<u8>(text, " 300", "time")), "0:1: invalid time '300'");
+    }
+
+    #[test]
+    fn ints_with_signs_and_anything_around() {
+        let text = "p=<-3,14,0> v=-x 7--2";
+
+        assert_eq!(ints::<i32>(text, text, "number"), Ok(vec![-3, 14, 0, 7, -2]));
+        assert_eq!(ints::<i32>(text, "no numbers", "number"), Ok(vec![]));
+
+        let text = "a 12 99999999999";
+        assert_eq!(error(ints::<u32>(text, text, "number")), "0:6: invalid number '99999999999'");
+    }
+
+    #[test]
+    fn fields_and_how_many_there_are() {
+        let text = "R 6 (#70c710)";
+
+        assert_eq!(fields(text, text, "fields"), Ok(["R", "6", "(#70c710)"]));
+        assert_eq!(error(fields::<2>(text, text, "fields")), "0:1: expected 2 fields, found 3");
+        assert_eq!(error(fields::<3>(text, &text[2..], "fields")), "0:3: expected 3 fields, found 2");
+        assert_eq!(error(exactly::<u8, 2>(text, &text[4..], vec![1], "numbers")), "0:5: expected 2 numbers, found 1");
+    }
+
+    #[test]
+    fn split_once_trims_both_sides() {
+        let text = "AAA = (BBB, CCC)";
+
+        assert_eq!(split_once(text, text, "=", "the next nodes"), Ok(("AAA", "(BBB, CCC)")));
+        // a missing separator is reported at the end of the part
+        assert_eq!(error(split_once(text, text, "->", "the targets")), "0:17: missing '->' and the targets");
+        assert_eq!(error(split_once(text, &text[7..10], ",", "the right node")), "0:11: missing ',' and the right node");
+    }
+
+    #[test]
+    fn enclosed_and_records() {
+        let text = "px{a<2006:qkq,m>2090:A,rfg} (BBB, CCC) {x=787}";
+
+        assert_eq!(enclosed(text, &text[27..38], '(', ')'), Ok("BBB, CCC"));
+        assert_eq!(error(enclosed(text, &text[27..37], '(', ')')), "0:29: expected '(...)'");
+
+        assert_eq!(record(text, &text[..27]), Ok(("px", "a<2006:qkq,m>2090:A,rfg")));
+        assert_eq!(record(text, &text[38..]), Ok(("", "x=787")));
+        assert_eq!(error(record(text, &text[28..38])), "0:29: expected '<name>{...}'");
+        assert_eq!(error(record(text, "in{a<5:R")), "0:1: expected '{...}'");
+    }
+
+    #[test]
+    fn after_a_label() {
+        let text = "Card  1: 41 48";
+
+        assert_eq!(after(text, text, "Card"), Ok("1: 41 48"));
+        assert_eq!(error(after(text, &text[7..], "Game")), "0:8: expected 'Game'");
+    }
+
+    #[test]
+    fn blocks_count_the_lines_before_them() {
+        let input = "seeds: 1 2\n\na map:\n1 2 3\n4 5 6\n\n\nb map:\n7 8 9\n\n";
+
+        assert_eq!(blocks(input), vec![(0, "seeds: 1 2"), (2, "a map:\n1 2 3\n4 5 6"), (7, "b map:\n7 8 9")]);
+        assert_eq!(blocks("\n\n"), vec![]);
+    }
+
+    #[test]
+    fn errors_get_their_line() {
+        let input = "1 2\n3 x\n";
+        let parse = |text: &str| list::<u32>(text, text, "number");
+        assert_eq!(error(parse_lines(input, parse)), "2:3: invalid number 'x'");
+
+        // in a block, the line is moved down by the lines before it
+        let input = "1\n\n1\n2 x\n";
+        let result = parse_blocks(input, |block| parse_lines(block, parse));
+        assert_eq!(error(result), "4:3: invalid number 'x'");
+
+        // an error about the whole block without a line is on its first line
+        assert_eq!(ParseError::new(0, 1, "empty").shifted(2), ParseError::new(3, 1, "empty"));
+    }
+
+    #[test]
+    fn excerpt_points_at_the_column() {
+        let text = "1 2\n3 x\n";
+
+        assert_eq!(excerpt(text, &ParseError::new(2, 3, "invalid")), Some("  3 x\n    ^".to_string()));
+        assert_eq!(excerpt(text, &ParseError::new(0, 3, "invalid")), None);
+        assert_eq!(excerpt(text, &ParseError::new(5, 1, "invalid")), None);
+    }
+}
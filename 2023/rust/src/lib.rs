@@ -0,0 +1,18 @@
+//! The Advent of Code 2023 solutions, with the `aoc` binary as a thin command line over them.
+//!
+//! Each day is a module of `days` (e.g. `days::d17`) with a type implementing `solution::Solution`,
+//! and `days::DAYS` lists them all. The grid, search, math, geometry and range code the days share is in `utils`.
+
+pub mod animate;
+pub mod bench;
+pub mod days;
+pub mod generate;
+pub mod input;
+pub mod render;
+pub mod results;
+pub mod solution;
+pub mod utils;
+pub mod verify;
+
+#[cfg(test)]
+mod differential;
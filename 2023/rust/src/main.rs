@@ -0,0 +1,633 @@
+use std::env;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::time::{Duration, Instant};
+
+use rayon::prelude::*;
+
+use advent_of_code_2023::{animate, bench, days, generate, input, render, results, verify};
+use advent_of_code_2023::days::Day;
+use advent_of_code_2023::input::InputError;
+use advent_of_code_2023::results::{DayResult, Format, PartResult, Status};
+use advent_of_code_2023::solution::Puzzle;
+
+const USAGE: &str = "Usage:
+    aoc list
+    aoc run <day|all> [--part <1|2>] [--input <path|->] [--example <n>] [--render <dir>] [--render-format <ppm|svg>] [--animate] [--threads <n>]
+        [--format <text|json|tsv>]
+    aoc verify [<day|all>] [--part <1|2>] [--input <file>]
+    aoc generate <day> [--seed <n>] [--size <n>] [--check] [--part <1|2>]
+    aoc bench [<day|all>] [--iterations <n>] [--example <n>] [--output <file>] [--baseline <file>] [--threshold <percent>]
+
+The input defaults to ./input/NN_input.txt, or to the AOC_INPUT environment variable when set.
+'-' reads the input from stdin and --example <n> reads ./input/NN_input_test<n>.txt, with the example's parameters
+where they differ from the real puzzle (also when --input is one of the example files). With 'all' it skips the
+days without that example.
+--render writes pictures of the grid days' final state into <dir>, as PPM unless --render-format says svg.
+--animate plays the simulation days step by step in the terminal.
+--threads sets how many threads run the days (all of them at once with 'all') and their parallel loops,
+it defaults to one per CPU. The answers are the same whatever the number.
+--format json prints an array with the day, input, status, error, time and answers of every day run, and
+--format tsv a line per answer with the same fields, for other programs to read.
+verify checks the answers against the known ones in answers.toml, optionally only for one input file.
+generate prints a random input for the day (the same one for the same seed and size), or with --check
+solves it and compares with the answers it was built to have.
+bench times parsing and each part of the days over --iterations runs (10 by default) and prints the median and p95,
+--output writes them as JSON, and --baseline compares with an earlier --output, failing when a phase got more than
+--threshold percent slower (10 by default).";
+
+const INPUT_VAR: &str = "AOC_INPUT";
+
+enum Target {
+    All,
+    Day(&'static Day)
+}
+
+// where the puzzle input is read from, when not the day's default file
+enum Source {
+    File(String),
+    Example(u8),
+    Stdin
+}
+
+struct RunArgs {
+    target: Target,
+    part: Option<u8>,
+    source: Option<Source>,
+    render_dir: Option<String>,
+    render_format: render::Format,
+    animate: bool,
+    threads: Option<usize>,
+    format: Format
+}
+
+struct GenerateArgs {
+    day: &'static Day,
+    seed: u64,
+    size: usize,
+    check: bool,
+    part: Option<u8>
+}
+
+struct BenchArgs {
+    day: Option<&'static Day>,
+    iterations: usize,
+    example: Option<u8>,
+    output: Option<String>,
+    baseline: Option<String>,
+    threshold: f64
+}
+
+struct VerifyArgs {
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>
+}
+
+fn parse_day(text: &str) -> Result<&'static Day, String> {
+    let day: u8 = text.parse().map_err(|_| format!("Invalid day '{}'", text))?;
+
+    days::get(day).ok_or(format!("There is no solution for day {}", day))
+}
+
+fn parse_part(text: &str) -> Result<u8, String> {
+    match text {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("Invalid part '{}'", text))
+    }
+}
+
+fn parse_input(text: &str) -> Source {
+    if text == "-" {
+        Source::Stdin
+    } else {
+        Source::File(text.to_string())
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut it = args.iter();
+
+    let target = match it.next().map(|s| s.as_str()) {
+        Some("all") => Target::All,
+        Some(day) => Target::Day(parse_day(day)?),
+        None => return Err("Missing day to run".to_string())
+    };
+
+    let mut part = None;
+    let mut source = None;
+    let mut render_dir = None;
+    let mut render_format = render::Format::Ppm;
+    let mut animate = false;
+    let mut threads = None;
+    let mut format = Format::Text;
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = it.next().ok_or("Missing value for --part")?;
+                part = Some(parse_part(value)?);
+            },
+            "--input" | "--example" if source.is_some() => {
+                return Err("--input and --example can't be used together".to_string());
+            },
+            "--input" => {
+                let value = it.next().ok_or("Missing value for --input")?;
+                source = Some(parse_input(value));
+            },
+            "--example" => {
+                let value = it.next().ok_or("Missing value for --example")?;
+                source = match value.parse() {
+                    Ok(n) if n > 0 => Some(Source::Example(n)),
+                    _ => return Err(format!("Invalid example '{}'", value))
+                };
+            },
+            "--render" => {
+                let value = it.next().ok_or("Missing value for --render")?;
+                render_dir = Some(value.clone());
+            },
+            "--render-format" => {
+                let value = it.next().ok_or("Missing value for --render-format")?;
+                render_format = render::Format::parse(value).ok_or(format!("Invalid render format '{}'", value))?;
+            },
+            "--animate" => animate = true,
+            "--threads" => {
+                let value = it.next().ok_or("Missing value for --threads")?;
+                threads = match value.parse() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(format!("Invalid number of threads '{}'", value))
+                };
+            },
+            "--format" => {
+                let value = it.next().ok_or("Missing value for --format")?;
+                format = Format::parse(value).ok_or(format!("Invalid format '{}'", value))?;
+            },
+            _ => return Err(format!("Unknown argument '{}'", arg))
+        }
+    }
+
+    if source.is_none() {
+        source = env::var(INPUT_VAR).ok().map(|value| parse_input(&value));
+    }
+
+    if matches!(source, Some(Source::File(_) | Source::Stdin)) && matches!(target, Target::All) {
+        return Err(format!("--input ({}) can only be used when running a single day", INPUT_VAR));
+    }
+
+    if format != Format::Text && (render_dir.is_some() || animate) {
+        return Err("--render and --animate only work with the text format".to_string());
+    }
+
+    Ok(RunArgs { target, part, source, render_dir, render_format, animate, threads, format })
+}
+
+/**
+ * What running a day printed, kept until it is its turn when the days run in parallel,
+ * so the output is in day order.
+ */
+#[derive(Default)]
+struct Output {
+    stdout: String,
+    stderr: String
+}
+
+impl Output {
+    fn print(&self) {
+        print!("{}", self.stdout);
+        eprint!("{}", self.stderr);
+    }
+}
+
+// Writes the day's pictures into `dir`, returns whether it worked
+fn render_day(day: &Day, text: &str, puzzle: Puzzle, dir: &str, format: render::Format, out: &mut Output) -> bool {
+    let frames = match (day.render)(text, puzzle) {
+        Ok(frames) => frames,
+        Err(_) => return false
+    };
+
+    if frames.is_empty() {
+        writeln!(out.stdout, "Day {:02} has nothing to render", day.day).unwrap();
+        return true;
+    }
+
+    match render::write_frames(Path::new(dir), day.day, &frames, format) {
+        Ok(files) => {
+            for file in files {
+                writeln!(out.stdout, "Rendered {}", file.display()).unwrap();
+            }
+            true
+        },
+        Err(e) => {
+            writeln!(out.stderr, "Could not render into {}: {}", dir, e).unwrap();
+            false
+        }
+    }
+}
+
+// Plays the day's simulation in the terminal, returns whether it worked
+fn animate_day(day: &Day, text: &str, puzzle: Puzzle) -> bool {
+    let frames = match (day.animate)(text, puzzle) {
+        Ok(frames) => frames,
+        Err(_) => return false
+    };
+
+    if frames.is_empty() {
+        println!("Day {:02} has nothing to animate", day.day);
+        return true;
+    }
+
+    match animate::play(&frames) {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("Could not animate day {:02}: {}", day.day, e);
+            false
+        }
+    }
+}
+
+// The file to read the day's input from, None for stdin, and which puzzle it is for
+fn day_input(day: &Day, source: &Option<Source>) -> (Option<String>, Puzzle) {
+    match source {
+        None => (Some(day.input_file()), Puzzle::Real),
+        Some(Source::File(path)) => (Some(path.clone()), day.puzzle_of(path)),
+        Some(Source::Example(n)) => (Some(day.example_file(*n)), Puzzle::Example),
+        Some(Source::Stdin) => (None, Puzzle::Real)
+    }
+}
+
+/**
+ * Reads and solves the day, the answers or a diagnostic pointing at the input problem go to `out` as text.
+ * Returns the result for the other formats, and the input when it was solved, for --render and --animate.
+ */
+fn run_day(day: &'static Day, args: &RunArgs, out: &mut Output) -> (DayResult, Option<String>) {
+    let (input_file, puzzle) = day_input(day, &args.source);
+
+    let input_name = input_file.as_deref().unwrap_or("stdin");
+    let mut result = DayResult {
+        day: day.day,
+        name: day.name,
+        input: input_name.to_string(),
+        status: Status::Ok,
+        error: None,
+        time_ns: 0,
+        answers: vec![]
+    };
+
+    writeln!(out.stdout, "== Day {:02}: {} ({})", day.day, day.name, input_name).unwrap();
+
+    let text = match input::read_input(input_file.as_deref()) {
+        Ok(text) => text,
+        Err(e) => {
+            writeln!(out.stderr, "Could not read the puzzle input {}", e).unwrap();
+            result.status = Status::UnreadableInput;
+            result.error = Some(e.to_string());
+            return (result, None);
+        }
+    };
+
+    let start = Instant::now();
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| (day.solve)(&text, args.part, puzzle)));
+    result.time_ns = start.elapsed().as_nanos() as u64;
+
+    match solved {
+        Ok(Ok(answers)) => {
+            for answer in answers {
+                writeln!(out.stdout, "[Part {}] {}: {}", answer.part, answer.label, answer.value).unwrap();
+                result.answers.push(PartResult::from(answer));
+            }
+
+            (result, Some(text))
+        },
+        Ok(Err(error)) => {
+            let excerpt = input::excerpt(&text, &error);
+            let error = InputError::Parse { file: input_name.to_string(), error };
+            writeln!(out.stderr, "Invalid puzzle input {}", error).unwrap();
+            if let Some(excerpt) = excerpt {
+                writeln!(out.stderr, "{}", excerpt).unwrap();
+            }
+            result.status = Status::InvalidInput;
+            result.error = Some(error.to_string());
+
+            (result, None)
+        },
+        Err(payload) => {
+            let message = verify::panic_message(payload.as_ref());
+            writeln!(out.stderr, "Day {:02} panicked: {}", day.day, message).unwrap();
+            result.status = Status::Panicked;
+            result.error = Some(message);
+
+            (result, None)
+        }
+    }
+}
+
+fn run(args: RunArgs) {
+    if let Some(threads) = args.threads {
+        rayon::ThreadPoolBuilder::new().num_threads(threads).build_global()
+            .expect("the thread pool was already started");
+    }
+
+    // the days don't all have the same examples, so 'all' only runs the ones that have the one asked for
+    let days: Vec<&'static Day> = match (&args.target, &args.source) {
+        (Target::All, Some(Source::Example(n))) => days::DAYS.iter().filter(|day| Path::new(&day.example_file(*n)).exists()).collect(),
+        (Target::All, _) => days::DAYS.iter().collect(),
+        (Target::Day(day), _) => vec![*day]
+    };
+
+    // keep going so one broken input does not hide the other days
+    let mut failed = 0;
+    if args.animate {
+        // the animations take the terminal one day at a time
+        for day in days {
+            let mut out = Output::default();
+            let (_, text) = run_day(day, &args, &mut out);
+            out.print();
+            if !text.is_some_and(|text| animate_day(day, &text, day_input(day, &args.source).1)) {
+                failed += 1;
+            }
+        }
+    } else {
+        let runs: Vec<(Output, DayResult, bool)> = days.par_iter()
+            .map(|day| {
+                let mut out = Output::default();
+                let (result, text) = run_day(day, &args, &mut out);
+                let ok = match (text, &args.render_dir) {
+                    (Some(text), Some(dir)) => render_day(day, &text, day_input(day, &args.source).1, dir, args.render_format, &mut out),
+                    (text, _) => text.is_some()
+                };
+                (out, result, ok)
+            })
+            .collect();
+
+        let mut results = vec![];
+        for (out, result, ok) in runs {
+            // the diagnostics stay on stderr whatever the format
+            match args.format {
+                Format::Text => out.print(),
+                Format::Json | Format::Tsv => eprint!("{}", out.stderr)
+            }
+            if !ok {
+                failed += 1;
+            }
+            results.push(result);
+        }
+
+        match args.format {
+            Format::Text => {},
+            Format::Json => print!("{}", results::json(&results)),
+            Format::Tsv => print!("{}", results::tsv(&results))
+        }
+    }
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+fn parse_verify_args(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = it.next().ok_or("Missing value for --part")?;
+                part = Some(parse_part(value)?);
+            },
+            "--input" => {
+                let value = it.next().ok_or("Missing value for --input")?;
+                input = Some(value.clone());
+            },
+            "all" => day = None,
+            _ if day.is_none() && !arg.starts_with("--") => day = Some(parse_day(arg)?.day),
+            _ => return Err(format!("Unknown argument '{}'", arg))
+        }
+    }
+
+    Ok(VerifyArgs { day, part, input })
+}
+
+fn verify(args: VerifyArgs) {
+    match verify::verify(verify::MANIFEST_FILE, args.day, args.part, args.input.as_deref()) {
+        Ok(true) => {},
+        Ok(false) => process::exit(1),
+        Err(msg) => {
+            eprintln!("{}", msg);
+            process::exit(2);
+        }
+    }
+}
+
+fn parse_generate_args(args: &[String]) -> Result<GenerateArgs, String> {
+    let mut it = args.iter();
+
+    let day = parse_day(it.next().ok_or("Missing day to generate")?)?;
+    let mut seed = generate::DEFAULT_SEED;
+    let mut size = generate::DEFAULT_SIZE;
+    let mut check = false;
+    let mut part = None;
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = it.next().ok_or("Missing value for --seed")?;
+                seed = value.parse().map_err(|_| format!("Invalid seed '{}'", value))?;
+            },
+            "--size" => {
+                let value = it.next().ok_or("Missing value for --size")?;
+                size = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid size '{}'", value))
+                };
+            },
+            "--check" => check = true,
+            "--part" => {
+                let value = it.next().ok_or("Missing value for --part")?;
+                part = Some(parse_part(value)?);
+            },
+            _ => return Err(format!("Unknown argument '{}'", arg))
+        }
+    }
+
+    if part.is_some() && !check {
+        return Err("--part can only be used with --check".to_string());
+    }
+
+    Ok(GenerateArgs { day, seed, size, check, part })
+}
+
+fn generate(args: GenerateArgs) {
+    let Some(generated) = generate::generate(args.day.day, args.seed, args.size) else {
+        eprintln!("There is no generator for day {}", args.day.day);
+        process::exit(2);
+    };
+
+    if !args.check {
+        print!("{}", generated.input);
+        return;
+    }
+
+    let name = format!("seed {}, size {}", args.seed, args.size);
+    if !verify::check_generated(args.day, &name, args.part, &generated) {
+        process::exit(1);
+    }
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut day = None;
+    let mut iterations = bench::DEFAULT_ITERATIONS;
+    let mut example = None;
+    let mut output = None;
+    let mut baseline = None;
+    let mut threshold = bench::DEFAULT_THRESHOLD;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--iterations" => {
+                let value = it.next().ok_or("Missing value for --iterations")?;
+                iterations = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid number of iterations '{}'", value))
+                };
+            },
+            "--example" => {
+                let value = it.next().ok_or("Missing value for --example")?;
+                example = match value.parse() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(format!("Invalid example '{}'", value))
+                };
+            },
+            "--output" => {
+                let value = it.next().ok_or("Missing value for --output")?;
+                output = Some(value.clone());
+            },
+            "--baseline" => {
+                let value = it.next().ok_or("Missing value for --baseline")?;
+                baseline = Some(value.clone());
+            },
+            "--threshold" => {
+                let value = it.next().ok_or("Missing value for --threshold")?;
+                threshold = match value.parse() {
+                    Ok(t) if t >= 0.0 => t,
+                    _ => return Err(format!("Invalid threshold '{}'", value))
+                };
+            },
+            "all" => day = None,
+            _ if day.is_none() && !arg.starts_with("--") => day = Some(parse_day(arg)?),
+            _ => return Err(format!("Unknown argument '{}'", arg))
+        }
+    }
+
+    Ok(BenchArgs { day, iterations, example, output, baseline, threshold })
+}
+
+fn nanos(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+fn bench(args: BenchArgs) {
+    // read first, so a bad baseline does not waste a whole run
+    let baseline = match args.baseline.as_deref().map(bench::Report::read).transpose() {
+        Ok(baseline) => baseline,
+        Err(msg) => {
+            eprintln!("{}", msg);
+            process::exit(2);
+        }
+    };
+
+    let days: Vec<&Day> = match args.day {
+        Some(day) => vec![day],
+        None => days::DAYS.iter().collect()
+    };
+
+    println!("{:<31} {:>21} {:>21} {:>21}", "", "parse", "part 1", "part 2");
+    println!("{:<31} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10}", "", "median", "p95", "median", "p95", "median", "p95");
+
+    // the days run one after the other so they don't compete for the CPU
+    let mut report = bench::Report::default();
+    let mut failed = 0;
+    for day in days {
+        let (file, puzzle) = match args.example {
+            Some(n) => (day.example_file(n), Puzzle::Example),
+            None => (day.input_file(), Puzzle::Real)
+        };
+        let text = match input::read_input(Some(&file)) {
+            Ok(text) => text,
+            Err(e) => {
+                eprintln!("Skipping day {:02}, could not read the puzzle input {}", day.day, e);
+                continue;
+            }
+        };
+
+        match bench::bench(day, &text, puzzle, args.iterations) {
+            Ok(timings) => {
+                print!("{:02} {:<28}", day.day, day.name);
+                for timing in timings.phases() {
+                    print!(" {:>10} {:>10}", nanos(timing.median_ns), nanos(timing.p95_ns));
+                }
+                println!();
+                report.days.push(timings);
+            },
+            Err(error) => {
+                eprintln!("Invalid puzzle input {}", InputError::Parse { file, error });
+                failed += 1;
+            }
+        }
+    }
+
+    if let Some(path) = &args.output {
+        if let Err(msg) = report.write(path) {
+            eprintln!("{}", msg);
+            process::exit(2);
+        }
+    }
+
+    if let Some(baseline) = baseline {
+        let regressions = bench::regressions(&baseline, &report, args.threshold);
+        println!();
+        for regression in &regressions {
+            println!("REGRESSION day {:02} {}: {} -> {} (+{:.0}%)", regression.day, regression.phase,
+                nanos(regression.baseline_ns), nanos(regression.current_ns), regression.percent());
+        }
+        println!("{} regressions above {}% against the baseline", regressions.len(), args.threshold);
+        failed += regressions.len();
+    }
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+fn list() {
+    for day in &days::DAYS {
+        println!("{:02} {:<26} {}", day.day, day.name, day.input_file());
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("list") => {
+            list();
+            Ok(())
+        },
+        Some("run") => parse_run_args(&args[1..]).map(run),
+        Some("verify") => parse_verify_args(&args[1..]).map(verify),
+        Some("generate") => parse_generate_args(&args[1..]).map(generate),
+        Some("bench") => parse_bench_args(&args[1..]).map(bench),
+        Some(cmd) => Err(format!("Unknown command '{}'", cmd)),
+        None => Err("Missing command".to_string())
+    };
+
+    if let Err(msg) = result {
+        eprintln!("{}\n\n{}", msg, USAGE);
+        process::exit(2);
+    }
+}
@@ -0,0 +1,183 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::utils::grid::Grid;
+
+// pixels (or svg units) per grid cell
+const SCALE: usize = 4;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const BLACK: Color = Color(0, 0, 0);
+    pub const WHITE: Color = Color(255, 255, 255);
+    pub const GREY: Color = Color(110, 110, 110);
+    pub const DARK_GREY: Color = Color(40, 40, 40);
+    pub const RED: Color = Color(220, 40, 40);
+    pub const GREEN: Color = Color(60, 180, 75);
+    pub const BLUE: Color = Color(50, 90, 200);
+    pub const YELLOW: Color = Color(250, 210, 50);
+    pub const BROWN: Color = Color(140, 90, 40);
+
+    // the color `t` of the way from `self` to `other`, with `t` between 0 and 1
+    pub fn blend(self, other: Color, t: f64) -> Color {
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t.clamp(0.0, 1.0)).round() as u8;
+
+        Color(mix(self.0, other.0), mix(self.1, other.1), mix(self.2, other.2))
+    }
+}
+
+/**
+ * A picture of a grid puzzle with one colored cell per grid position.
+ * `name` ends up in the file name, so it should be short and without spaces.
+ */
+pub struct Frame {
+    pub name: String,
+    pub cells: Grid<Color>
+}
+
+impl Frame {
+    pub fn new<T>(name: &str, grid: &Grid<T>, color: impl Fn(&T) -> Color) -> Self {
+        Self { name: name.to_string(), cells: grid.map(color) }
+    }
+
+    // paints `positions` over the picture, e.g. a path or the visited tiles
+    pub fn overlay(mut self, positions: impl IntoIterator<Item = (usize, usize)>, color: Color) -> Self {
+        for pos in positions {
+            self.cells[pos] = color;
+        }
+
+        self
+    }
+
+    // binary (P6) PPM
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let (width, height) = (self.cells.width() * SCALE, self.cells.height() * SCALE);
+        let mut data = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+
+        for row in self.cells.rows() {
+            let mut line = Vec::with_capacity(width * 3);
+            for Color(r, g, b) in row {
+                for _ in 0..SCALE {
+                    line.extend([*r, *g, *b]);
+                }
+            }
+            for _ in 0..SCALE {
+                data.extend(&line);
+            }
+        }
+
+        data
+    }
+
+    // one rect per run of equally colored cells in a row
+    pub fn to_svg(&self) -> String {
+        let (width, height) = (self.cells.width(), self.cells.height());
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+            width * SCALE, height * SCALE, width, height);
+
+        for (y, row) in self.cells.rows().enumerate() {
+            let mut x = 0;
+            for run in row.chunk_by(|a, b| a == b) {
+                let Color(r, g, b) = run[0];
+                writeln!(svg, "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\" fill=\"#{:02x}{:02x}{:02x}\"/>", x, y, run.len(), r, g, b).unwrap();
+                x += run.len();
+            }
+        }
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Svg
+}
+
+impl Format {
+    pub fn parse(text: &str) -> Option<Self> {
+        match text {
+            "ppm" => Some(Format::Ppm),
+            "svg" => Some(Format::Svg),
+            _ => None
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Svg => "svg"
+        }
+    }
+}
+
+// Writes every frame into `dir` as NN_<name>.<ext>, creating it when needed, returns the files written
+pub fn write_frames(dir: &Path, day: u8, frames: &[Frame], format: Format) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+
+    frames.iter().map(|frame| {
+        let file = dir.join(format!("{:02}_{}.{}", day, frame.name, format.extension()));
+        match format {
+            Format::Ppm => fs::write(&file, frame.to_ppm())?,
+            Format::Svg => fs::write(&file, frame.to_svg())?
+        }
+        Ok(file)
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a 3x2 black frame, with red then green painted over part of the first row
+    fn frame() -> Frame {
+        Frame { name: "test".to_string(), cells: Grid::from_fn(3, 2, |_| Color::BLACK) }
+            .overlay([(0, 0), (1, 0)], Color::RED)
+            .overlay([(1, 0)], Color::GREEN)
+    }
+
+    #[test]
+    fn later_overlays_paint_over_earlier_ones() {
+        let frame = frame();
+
+        assert_eq!(frame.cells[(0, 0)], Color::RED);
+        assert_eq!(frame.cells[(1, 0)], Color::GREEN);
+        assert_eq!(frame.cells[(2, 0)], Color::BLACK);
+        assert_eq!(frame.cells[(1, 1)], Color::BLACK);
+    }
+
+    #[test]
+    fn ppm_scales_every_cell() {
+        let ppm = frame().to_ppm();
+        let header = b"P6\n12 8\n255\n";
+
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 12 * 8 * 3);
+
+        // the first pixel row: 4 red pixels, then 4 green ones
+        let pixels = &ppm[header.len()..];
+        assert_eq!(&pixels[..3], &[220, 40, 40]);
+        assert_eq!(&pixels[3 * 3..4 * 3], &[220, 40, 40]);
+        assert_eq!(&pixels[4 * 3..5 * 3], &[60, 180, 75]);
+        // and the last one is black
+        assert_eq!(&pixels[pixels.len() - 3..], &[0, 0, 0]);
+    }
+
+    #[test]
+    fn svg_has_a_rect_per_run_of_colors() {
+        let svg = frame().to_svg();
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"12\" height=\"8\" viewBox=\"0 0 3 2\""));
+        assert_eq!(svg.matches("<rect ").count(), 4);
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"1\" height=\"1\" fill=\"#dc2828\"/>"));
+        assert!(svg.contains("<rect x=\"1\" y=\"0\" width=\"1\" height=\"1\" fill=\"#3cb44b\"/>"));
+        assert!(svg.contains("<rect x=\"0\" y=\"1\" width=\"3\" height=\"1\" fill=\"#000000\"/>"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
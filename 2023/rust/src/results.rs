@@ -0,0 +1,167 @@
+use serde::{Serialize, Serializer};
+
+use crate::solution::Answer;
+
+/**
+ * How `aoc run` prints the answers: the sentences for people, or the same fields for every day
+ * for other programs to read.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Tsv
+}
+
+impl Format {
+    pub fn parse(text: &str) -> Option<Self> {
+        match text {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "tsv" => Some(Format::Tsv),
+            _ => None
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    UnreadableInput,
+    InvalidInput,
+    Panicked
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::UnreadableInput => "unreadable_input",
+            Status::InvalidInput => "invalid_input",
+            Status::Panicked => "panicked"
+        }
+    }
+}
+
+// by the same name in JSON as in TSV
+impl Serialize for Status {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartResult {
+    pub part: u8,
+    pub label: &'static str,
+    pub answer: String,
+    pub time_ns: u64
+}
+
+impl From<Answer> for PartResult {
+    fn from(answer: Answer) -> Self {
+        PartResult { part: answer.part, label: answer.label, answer: answer.value, time_ns: answer.time.as_nanos() as u64 }
+    }
+}
+
+/**
+ * One day run on one input. The time is the whole solve, parsing included, and the answers are only there
+ * when the status is ok, while the error says what went wrong otherwise.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayResult {
+    pub day: u8,
+    pub name: &'static str,
+    pub input: String,
+    pub status: Status,
+    pub error: Option<String>,
+    pub time_ns: u64,
+    pub answers: Vec<PartResult>
+}
+
+// A JSON array with every day
+pub fn json(results: &[DayResult]) -> String {
+    serde_json::to_string_pretty(results).unwrap() + "\n"
+}
+
+pub const TSV_HEADER: &str = "day\tpart\tanswer\tinput\ttime_ns\tstatus\terror";
+
+/**
+ * A header line, then a line per answer with the time of its part, or a single line without part or answer
+ * for a day that failed, with the time it took to fail.
+ */
+pub fn tsv(results: &[DayResult]) -> String {
+    // the error messages are the only free text
+    let field = |text: &str| text.replace(['\t', '\n', '\r'], " ");
+
+    let mut lines = vec![TSV_HEADER.to_string()];
+    for result in results {
+        let status = result.status.name();
+        let error = result.error.as_deref().map(field).unwrap_or_default();
+
+        if result.answers.is_empty() {
+            lines.push(format!("{}\t\t\t{}\t{}\t{}\t{}", result.day, result.input, result.time_ns, status, error));
+        }
+        for answer in &result.answers {
+            lines.push(format!("{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                result.day, answer.part, field(&answer.answer), result.input, answer.time_ns, status, error));
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results() -> Vec<DayResult> {
+        vec![
+            DayResult {
+                day: 5,
+                name: "seed_fertilizer",
+                input: "./input/05_input.txt".to_string(),
+                status: Status::Ok,
+                error: None,
+                time_ns: 3000,
+                answers: vec![
+                    PartResult { part: 1, label: "Lowest location number", answer: "35".to_string(), time_ns: 1000 },
+                    PartResult { part: 2, label: "Lowest location number of the ranges", answer: "46".to_string(), time_ns: 1500 }
+                ]
+            },
+            DayResult {
+                day: 6,
+                name: "wait_for_it",
+                input: "stdin".to_string(),
+                status: Status::InvalidInput,
+                error: Some("1:6: invalid time\n\tTime: x".to_string()),
+                time_ns: 20,
+                answers: vec![]
+            }
+        ]
+    }
+
+    #[test]
+    fn json_has_every_field() {
+        let json: serde_json::Value = serde_json::from_str(&json(&results())).unwrap();
+
+        assert_eq!(json[0]["day"], 5);
+        assert_eq!(json[0]["status"], "ok");
+        assert_eq!(json[0]["error"], serde_json::Value::Null);
+        assert_eq!(json[0]["answers"][1]["answer"], "46");
+        assert_eq!(json[0]["answers"][1]["time_ns"], 1500);
+        assert_eq!(json[1]["status"], "invalid_input");
+        assert_eq!(json[1]["answers"].as_array().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn tsv_has_a_line_per_answer_or_failure() {
+        assert_eq!(tsv(&results()), [
+            TSV_HEADER,
+            "5\t1\t35\t./input/05_input.txt\t1000\tok\t",
+            "5\t2\t46\t./input/05_input.txt\t1500\tok\t",
+            "6\t\t\tstdin\t20\tinvalid_input\t1:6: invalid time  Time: x",
+            ""
+        ].join("\n"));
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::Display;
+use std::hint;
+use std::time::{Duration, Instant};
+
+use crate::input::ParseResult;
+use crate::render::Frame;
+
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    // what each answer means, printed next to it
+    const PART1: &'static str;
+    const PART2: &'static str;
+
+    fn parse(input: &str) -> ParseResult<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    // pictures of the final state for --render, only the grid puzzles have any
+    fn render(_input: &Self::Input) -> Vec<Frame> {
+        vec![]
+    }
+
+    // the successive states of the simulation days for --animate
+    fn animate(_input: &Self::Input) -> Vec<Frame> {
+        vec![]
+    }
+
+    // the examples of a few days come with other numbers than the real puzzle, such as fewer steps to take
+    fn example(input: Self::Input) -> Self::Input {
+        input
+    }
+}
+
+/**
+ * Which puzzle an input is for. The input alone can't tell, so it comes from how the input was chosen.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Puzzle {
+    Real,
+    Example
+}
+
+fn parse<S: Solution>(text: &str, puzzle: Puzzle) -> ParseResult<S::Input> {
+    let input = S::parse(text)?;
+
+    Ok(match puzzle {
+        Puzzle::Real => input,
+        Puzzle::Example => S::example(input)
+    })
+}
+
+// A missing part means both parts should run.
+pub fn runs_part(part: Option<u8>, n: u8) -> bool {
+    part.is_none_or(|p| p == n)
+}
+
+pub struct Answer {
+    pub part: u8,
+    pub label: &'static str,
+    pub value: String,
+    // how long the part took, without parsing
+    pub time: Duration
+}
+
+// the answer of one part and how long it took
+fn timed<T: Display>(part: u8, label: &'static str, solve: impl FnOnce() -> T) -> Answer {
+    let start = Instant::now();
+    let value = solve().to_string();
+
+    Answer { part, label, value, time: start.elapsed() }
+}
+
+pub fn solve<S: Solution>(text: &str, part: Option<u8>, puzzle: Puzzle) -> ParseResult<Vec<Answer>> {
+    let input = parse::<S>(text, puzzle)?;
+    let mut answers = Vec::new();
+
+    if runs_part(part, 1) {
+        answers.push(timed(1, S::PART1, || S::part1(&input)));
+    }
+    if runs_part(part, 2) {
+        answers.push(timed(2, S::PART2, || S::part2(&input)));
+    }
+
+    Ok(answers)
+}
+
+pub fn render<S: Solution>(text: &str, puzzle: Puzzle) -> ParseResult<Vec<Frame>> {
+    Ok(S::render(&parse::<S>(text, puzzle)?))
+}
+
+pub fn animate<S: Solution>(text: &str, puzzle: Puzzle) -> ParseResult<Vec<Frame>> {
+    Ok(S::animate(&parse::<S>(text, puzzle)?))
+}
+
+// How long parsing and each of the parts take on `text`, for `aoc bench`
+pub fn time<S: Solution>(text: &str, puzzle: Puzzle) -> ParseResult<[Duration; 3]> {
+    let start = Instant::now();
+    let input = parse::<S>(text, puzzle)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    hint::black_box(S::part1(&input));
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    hint::black_box(S::part2(&input));
+    let part2 = start.elapsed();
+
+    Ok([parse, part1, part2])
+}
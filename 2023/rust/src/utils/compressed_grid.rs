@@ -0,0 +1,133 @@
+use std::ops::{Index, IndexMut, Range};
+
+use crate::utils::grid::Grid;
+
+/**
+ * A huge, mostly empty grid stored as a small one by coordinate compression: the plane between the lowest and highest
+ * cut coordinates is split at every cut into cells, and each cell stands for a whole rectangle of tiles with the same
+ * value. A cell (column, row) spans xs[column]..xs[column + 1] and ys[row]..ys[row + 1].
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedGrid<T> {
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+    cells: Grid<T>
+}
+
+impl<T: Clone> CompressedGrid<T> {
+    /**
+     * The cells between the cut coordinates, in any order and repeated or not, all holding `value`.
+     * To tell a rectangle of tiles apart later its start and end (excluded) must be among the cuts.
+     */
+    pub fn new(xs: impl IntoIterator<Item = i64>, ys: impl IntoIterator<Item = i64>, value: T) -> Self {
+        let cuts = |coords: Vec<i64>| {
+            let mut coords = coords;
+            coords.sort_unstable();
+            coords.dedup();
+            assert!(coords.len() >= 2, "a compressed grid needs two different cuts in each direction");
+            coords
+        };
+        let (xs, ys) = (cuts(xs.into_iter().collect()), cuts(ys.into_iter().collect()));
+        let cells = Grid::from_fn(xs.len() - 1, ys.len() - 1, |_| value.clone());
+
+        Self { xs, ys, cells }
+    }
+
+    /**
+     * Sets every cell of the tiles x, y (ends excluded) to `value`.
+     * Panics when the rectangle does not start and end on cuts, as it would only cover part of a cell.
+     */
+    pub fn fill(&mut self, x: Range<i64>, y: Range<i64>, value: T) {
+        let columns = cut_index(&self.xs, x.start)..cut_index(&self.xs, x.end);
+        let rows = cut_index(&self.ys, y.start)..cut_index(&self.ys, y.end);
+
+        for row in rows {
+            for column in columns.clone() {
+                self.cells[(column, row)] = value.clone();
+            }
+        }
+    }
+}
+
+impl<T> CompressedGrid<T> {
+    // the cells, to walk through them like any grid
+    pub fn cells(&self) -> &Grid<T> {
+        &self.cells
+    }
+
+    // the cell holding the tile (x, y), None outside of the cuts
+    pub fn cell_of(&self, (x, y): (i64, i64)) -> Option<(usize, usize)> {
+        let column = self.xs.partition_point(|c| *c <= x).checked_sub(1)?;
+        let row = self.ys.partition_point(|c| *c <= y).checked_sub(1)?;
+
+        (column < self.cells.width() && row < self.cells.height()).then_some((column, row))
+    }
+
+    // the tiles a cell stands for
+    pub fn span(&self, (column, row): (usize, usize)) -> (Range<i64>, Range<i64>) {
+        (self.xs[column]..self.xs[column + 1], self.ys[row]..self.ys[row + 1])
+    }
+
+    // how many tiles a cell stands for
+    pub fn area(&self, cell: (usize, usize)) -> u64 {
+        let (x, y) = self.span(cell);
+        (x.end - x.start) as u64 * (y.end - y.start) as u64
+    }
+}
+
+fn cut_index(cuts: &[i64], coord: i64) -> usize {
+    cuts.binary_search(&coord).unwrap_or_else(|_| panic!("{} is not a cut of the compressed grid", coord))
+}
+
+impl<T> Index<(usize, usize)> for CompressedGrid<T> {
+    type Output = T;
+
+    fn index(&self, cell: (usize, usize)) -> &T {
+        &self.cells[cell]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for CompressedGrid<T> {
+    fn index_mut(&mut self, cell: (usize, usize)) -> &mut T {
+        &mut self.cells[cell]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cells_stand_for_the_tiles_between_cuts() {
+        let grid = CompressedGrid::new([0, 1_000_000, 10, 10], [-5, 5], '.');
+
+        assert_eq!(grid.cells().width(), 2);
+        assert_eq!(grid.cells().height(), 1);
+        assert_eq!(grid.span((1, 0)), (10..1_000_000, -5..5));
+        assert_eq!(grid.area((0, 0)), 100);
+        assert_eq!(grid.area((1, 0)), 9_999_900);
+
+        assert_eq!(grid.cell_of((0, -5)), Some((0, 0)));
+        assert_eq!(grid.cell_of((10, 4)), Some((1, 0)));
+        assert_eq!(grid.cell_of((999_999, 0)), Some((1, 0)));
+        assert_eq!(grid.cell_of((1_000_000, 0)), None);
+        assert_eq!(grid.cell_of((-1, 0)), None);
+    }
+
+    #[test]
+    fn fill_covers_whole_cells() {
+        let mut grid = CompressedGrid::new([0, 2, 5, 9], [0, 3, 4], 0);
+        grid.fill(2..9, 3..4, 1);
+
+        let filled: u64 = grid.cells().iter().filter(|(_, v)| **v == 1).map(|(cell, _)| grid.area(cell)).sum();
+        assert_eq!(filled, 7);
+        assert_eq!(grid[(0, 1)], 0);
+        assert_eq!(grid[(2, 1)], 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "3 is not a cut")]
+    fn fill_must_start_on_a_cut() {
+        CompressedGrid::new([0, 2, 5], [0, 1], false).fill(3..5, 0..1, true);
+    }
+}
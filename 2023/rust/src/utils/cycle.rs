@@ -0,0 +1,113 @@
+use std::hash::Hash;
+
+use indexmap::IndexSet;
+
+/**
+ * The states a simulation goes through until it repeats itself: the first `prefix` states are
+ * only seen once, then the next `period` states loop forever.
+ */
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+    pub prefix: usize,
+    pub period: usize,
+    // every state from the start (step 0), until just before the first repeated one
+    pub history: Vec<S>
+}
+
+impl<S> Cycle<S> {
+    // the state after `n` steps
+    pub fn state_at(&self, n: usize) -> &S {
+        if n < self.prefix {
+            &self.history[n]
+        } else {
+            &self.history[self.prefix + (n - self.prefix) % self.period]
+        }
+    }
+
+    // the steps, from 0 to the end of the first loop, where `f` holds
+    pub fn steps_where(&self, mut f: impl FnMut(&S) -> bool) -> Vec<usize> {
+        self.history.iter().enumerate().filter(|(_, s)| f(s)).map(|(i, _)| i).collect()
+    }
+}
+
+/**
+ * Runs `step` from `start` until a state comes back, comparing whole states.
+ * It never returns when the simulation does not loop, so the states must be finite.
+ */
+pub fn find<S, F>(start: S, mut step: F) -> Cycle<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S
+{
+    let mut seen: IndexSet<S> = IndexSet::new();
+    let mut state = start;
+
+    loop {
+        if let Some(index) = seen.get_index_of(&state) {
+            let history: Vec<S> = seen.into_iter().collect();
+            return Cycle { prefix: index, period: history.len() - index, history };
+        }
+
+        let next = step(&state);
+        seen.insert(state);
+        state = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, then 5, 6, 7 forever
+    fn rho() -> Cycle<u32> {
+        find(0, |n| if *n == 7 { 5 } else { n + 1 })
+    }
+
+    #[test]
+    fn cycle_after_a_prefix() {
+        let cycle = rho();
+
+        assert_eq!((cycle.prefix, cycle.period), (5, 3));
+        assert_eq!(cycle.history, vec![0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn state_in_the_prefix_and_far_past_it() {
+        let cycle = rho();
+
+        assert_eq!(*cycle.state_at(0), 0);
+        assert_eq!(*cycle.state_at(4), 4);
+        assert_eq!(*cycle.state_at(5), 5);
+        assert_eq!(*cycle.state_at(8), 5);
+        assert_eq!(*cycle.state_at(1_000_000_000), 5 + (1_000_000_000 - 5) % 3);
+        assert_eq!(*cycle.state_at(usize::MAX), 5 + ((usize::MAX - 5) % 3) as u32);
+    }
+
+    #[test]
+    fn period_of_one() {
+        // stuck at 3 once it gets there
+        let cycle = find(0u32, |n| (n + 1).min(3));
+
+        assert_eq!((cycle.prefix, cycle.period), (3, 1));
+        assert_eq!(*cycle.state_at(2), 2);
+        assert_eq!(*cycle.state_at(3), 3);
+        assert_eq!(*cycle.state_at(1 << 40), 3);
+    }
+
+    #[test]
+    fn loop_from_the_start() {
+        let cycle = find(1u32, |n| n * 3 % 7);
+
+        assert_eq!((cycle.prefix, cycle.period), (0, 6));
+        assert_eq!(*cycle.state_at(6), 1);
+        assert_eq!(*cycle.state_at(13), 3);
+    }
+
+    #[test]
+    fn steps_where_covers_the_prefix_and_the_first_loop() {
+        let cycle = rho();
+
+        assert_eq!(cycle.steps_where(|n| n % 2 == 1), vec![1, 3, 5, 7]);
+        assert_eq!(cycle.steps_where(|n| *n > 10), Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,157 @@
+/**
+ * One of the four cardinal directions on a grid, where up is towards row 0.
+ */
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+impl Direction {
+    // clockwise, starting up
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    // the (x, y) change of one step
+    pub fn offset(self) -> (i64, i64) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0)
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    // the direction after bouncing off a '/' or '\' mirror, anything else lets it through
+    pub fn reflect(self, mirror: char) -> Self {
+        match mirror {
+            '/' if self.is_horizontal() => self.turn_left(),
+            '/' => self.turn_right(),
+            '\\' if self.is_horizontal() => self.turn_right(),
+            '\\' => self.turn_left(),
+            _ => self
+        }
+    }
+
+    // U, R, D or L
+    pub fn from_letter(letter: &str) -> Option<Self> {
+        match letter {
+            "U" => Some(Direction::Up),
+            "R" => Some(Direction::Right),
+            "D" => Some(Direction::Down),
+            "L" => Some(Direction::Left),
+            _ => None
+        }
+    }
+
+    // ^, >, v or <
+    pub fn from_arrow(arrow: char) -> Option<Self> {
+        match arrow {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use Direction::{Down, Left, Right, Up};
+
+    #[test]
+    fn turns_and_opposites() {
+        // direction, left, right, opposite, letter, arrow
+        let table = [
+            (Up, Left, Right, Down, "U", '^'),
+            (Right, Up, Down, Left, "R", '>'),
+            (Down, Right, Left, Up, "D", 'v'),
+            (Left, Down, Up, Right, "L", '<')
+        ];
+
+        for (dir, left, right, opposite, letter, arrow) in table {
+            assert_eq!(dir.turn_left(), left, "{:?} turning left", dir);
+            assert_eq!(dir.turn_right(), right, "{:?} turning right", dir);
+            assert_eq!(dir.opposite(), opposite, "opposite of {:?}", dir);
+            assert_eq!(Direction::from_letter(letter), Some(dir));
+            assert_eq!(Direction::from_arrow(arrow), Some(dir));
+
+            // round trips
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_eq!(dir.turn_left().turn_left().turn_left().turn_left(), dir);
+
+            let (dx, dy) = dir.offset();
+            assert_eq!(dir.opposite().offset(), (-dx, -dy));
+            assert_eq!(dir.is_horizontal(), dy == 0);
+        }
+
+        assert_eq!(Direction::ALL.map(Direction::turn_right), [Right, Down, Left, Up]);
+    }
+
+    #[test]
+    fn reflections_off_mirrors() {
+        // coming in going `dir`, leaving after a '/' and after a '\'
+        let table = [
+            (Up, Right, Left),
+            (Right, Up, Down),
+            (Down, Left, Right),
+            (Left, Down, Up)
+        ];
+
+        for (dir, slash, backslash) in table {
+            assert_eq!(dir.reflect('/'), slash, "{:?} off '/'", dir);
+            assert_eq!(dir.reflect('\\'), backslash, "{:?} off '\\'", dir);
+            // a mirror sends back where it came from
+            assert_eq!(dir.reflect('/').opposite().reflect('/'), dir.opposite());
+            assert_eq!(dir.reflect('\\').opposite().reflect('\\'), dir.opposite());
+            assert_eq!(dir.reflect('.'), dir);
+            assert_eq!(dir.reflect('|'), dir);
+        }
+    }
+
+    #[test]
+    fn unknown_letters_and_arrows() {
+        assert_eq!(Direction::from_letter("u"), None);
+        assert_eq!(Direction::from_letter("UR"), None);
+        assert_eq!(Direction::from_letter(""), None);
+        assert_eq!(Direction::from_arrow('V'), None);
+        assert_eq!(Direction::from_arrow('.'), None);
+    }
+}
@@ -0,0 +1,389 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Sub};
+
+use crate::utils::math::{self, Rational};
+
+/**
+ * The numbers the points can have as coordinates, exact ones only: the integers or rationals.
+ * The arithmetic panics on overflow, in release builds too, as a wrapped cross product would silently give a
+ * wrong turn or area.
+ */
+pub trait Coordinate: Copy + Ord {
+    const ZERO: Self;
+
+    fn plus(self, other: Self) -> Self;
+    fn minus(self, other: Self) -> Self;
+    fn times(self, other: Self) -> Self;
+    fn negated(self) -> Self;
+    fn to_rational(self) -> Rational;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+
+                fn plus(self, other: Self) -> Self {
+                    self.checked_add(other).expect("overflow in coordinate arithmetic")
+                }
+
+                fn minus(self, other: Self) -> Self {
+                    self.checked_sub(other).expect("overflow in coordinate arithmetic")
+                }
+
+                fn times(self, other: Self) -> Self {
+                    self.checked_mul(other).expect("overflow in coordinate arithmetic")
+                }
+
+                fn negated(self) -> Self {
+                    self.checked_neg().expect("overflow in coordinate arithmetic")
+                }
+
+                fn to_rational(self) -> Rational {
+                    Rational::from(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i64, i128);
+
+// its own arithmetic is already checked
+impl Coordinate for Rational {
+    const ZERO: Self = Rational::ZERO;
+
+    fn plus(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn minus(self, other: Self) -> Self {
+        self - other
+    }
+
+    fn times(self, other: Self) -> Self {
+        self * other
+    }
+
+    fn negated(self) -> Self {
+        -self
+    }
+
+    fn to_rational(self) -> Rational {
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T
+}
+
+impl<T: Coordinate> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    // the z of the 3D cross product, positive when `other` turns counterclockwise from `self`
+    pub fn cross(self, other: Self) -> T {
+        self.x.times(other.y).minus(self.y.times(other.x))
+    }
+
+    pub fn to_rational(self) -> Point<Rational> {
+        Point::new(self.x.to_rational(), self.y.to_rational())
+    }
+}
+
+impl<T: Coordinate> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x.plus(other.x), self.y.plus(other.y))
+    }
+}
+
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x.minus(other.x), self.y.minus(other.y))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    Collinear,
+    CounterClockwise
+}
+
+// The turn taken going from p to q and then to r, with y going up (on a grid going down they are mirrored)
+pub fn orientation<T: Coordinate>(p: Point<T>, q: Point<T>, r: Point<T>) -> Orientation {
+    match (q - p).cross(r - p).cmp(&T::ZERO) {
+        Ordering::Greater => Orientation::CounterClockwise,
+        Ordering::Less => Orientation::Clockwise,
+        Ordering::Equal => Orientation::Collinear
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment<T> {
+    pub start: Point<T>,
+    pub end: Point<T>
+}
+
+impl<T: Coordinate> Segment<T> {
+    pub fn new(start: Point<T>, end: Point<T>) -> Self {
+        Self { start, end }
+    }
+
+    // whether the point is on the segment, the ends included
+    pub fn contains(&self, p: Point<T>) -> bool {
+        orientation(self.start, self.end, p) == Orientation::Collinear &&
+            p.x >= self.start.x.min(self.end.x) && p.x <= self.start.x.max(self.end.x) &&
+            p.y >= self.start.y.min(self.end.y) && p.y <= self.start.y.max(self.end.y)
+    }
+
+    // whether the segments share any point, so touching ends and overlapping collinear segments intersect
+    pub fn intersects(&self, other: &Self) -> bool {
+        let o1 = orientation(self.start, self.end, other.start);
+        let o2 = orientation(self.start, self.end, other.end);
+        let o3 = orientation(other.start, other.end, self.start);
+        let o4 = orientation(other.start, other.end, self.end);
+
+        // each segment has the ends of the other on both of its sides
+        let crossing = o1 != o2 && o3 != o4 && [o1, o2, o3, o4].iter().all(|o| *o != Orientation::Collinear);
+
+        crossing ||
+            self.contains(other.start) || self.contains(other.end) ||
+            other.contains(self.start) || other.contains(self.end)
+    }
+}
+
+/**
+ * The line through `point` going along `direction`: the points `point + t * direction` for every t.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<T> {
+    pub point: Point<T>,
+    pub direction: Point<T>
+}
+
+// Where two lines cross, `t` along the first one and `u` along the other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crossing {
+    pub point: Point<Rational>,
+    pub t: Rational,
+    pub u: Rational
+}
+
+impl<T: Coordinate> Line<T> {
+    pub fn new(point: Point<T>, direction: Point<T>) -> Self {
+        Self { point, direction }
+    }
+
+    pub fn at(&self, t: Rational) -> Point<Rational> {
+        let (point, direction) = (self.point.to_rational(), self.direction.to_rational());
+
+        Point::new(point.x + t * direction.x, point.y + t * direction.y)
+    }
+
+    // None when the lines are parallel, or the same line
+    pub fn crossing(&self, other: &Self) -> Option<Crossing> {
+        let den = self.direction.cross(other.direction);
+        if den == T::ZERO {
+            return None;
+        }
+
+        // point + t * direction = other.point + u * other.direction, crossed with each direction
+        let diff = other.point - self.point;
+        let t = diff.cross(other.direction).to_rational() / den.to_rational();
+        let u = diff.cross(self.direction).to_rational() / den.to_rational();
+
+        Some(Crossing { point: self.at(t), t, u })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside
+}
+
+/**
+ * A simple polygon: its vertices in order around it, either way, with the last one joined back to the first.
+ * The vertices can be on a straight edge, e.g. every tile of a loop on a grid.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon<T> {
+    vertices: Vec<Point<T>>
+}
+
+impl<T: Coordinate> Polygon<T> {
+    pub fn new(vertices: Vec<Point<T>>) -> Self {
+        assert!(!vertices.is_empty(), "a polygon needs vertices");
+
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point<T>] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = Segment<T>> + '_ {
+        self.vertices.iter().zip(self.vertices.iter().cycle().skip(1))
+            .map(|(start, end)| Segment::new(*start, *end))
+    }
+
+    // The shoelace formula, doubled so it stays whole on integer vertices
+    pub fn double_area(&self) -> T {
+        let sum = self.edges().fold(T::ZERO, |sum, edge| sum.plus(edge.start.cross(edge.end)));
+
+        if sum < T::ZERO { sum.negated() } else { sum }
+    }
+
+    pub fn area(&self) -> Rational {
+        self.double_area().to_rational() / Rational::from(2i64)
+    }
+
+    // By the winding number, which only needs the orientation of the point to each edge
+    pub fn locate(&self, p: Point<T>) -> Location {
+        let mut winding = 0;
+
+        for edge in self.edges() {
+            if edge.contains(p) {
+                return Location::Boundary;
+            }
+
+            let (a, b) = (edge.start, edge.end);
+            if a.y <= p.y {
+                if b.y > p.y && orientation(a, b, p) == Orientation::CounterClockwise {
+                    winding += 1;
+                }
+            } else if b.y <= p.y && orientation(a, b, p) == Orientation::Clockwise {
+                winding -= 1;
+            }
+        }
+
+        if winding == 0 { Location::Outside } else { Location::Inside }
+    }
+}
+
+impl Polygon<i64> {
+    // the points with integer coordinates on the edges
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|edge| math::gcd(edge.end.x.minus(edge.start.x).unsigned_abs(), edge.end.y.minus(edge.start.y).unsigned_abs()) as i64)
+            .fold(0, i64::plus)
+    }
+
+    // Pick's theorem: area = interior + boundary / 2 - 1
+    pub fn interior_points(&self) -> i64 {
+        self.double_area().minus(self.boundary_points()).plus(2) / 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: i64, y: i64) -> Point<i64> {
+        Point::new(x, y)
+    }
+
+    fn segment(a: (i64, i64), b: (i64, i64)) -> Segment<i64> {
+        Segment::new(p(a.0, a.1), p(b.0, b.1))
+    }
+
+    #[test]
+    fn orientation_of_turns() {
+        assert_eq!(orientation(p(0, 0), p(2, 0), p(2, 1)), Orientation::CounterClockwise);
+        assert_eq!(orientation(p(0, 0), p(2, 0), p(2, -1)), Orientation::Clockwise);
+        assert_eq!(orientation(p(0, 0), p(2, 0), p(5, 0)), Orientation::Collinear);
+    }
+
+    #[test]
+    fn segments_intersect_when_they_share_a_point() {
+        let s = segment((0, 0), (4, 4));
+
+        assert!(s.intersects(&segment((0, 4), (4, 0))));
+        // touching at an end, and overlapping on the same line
+        assert!(s.intersects(&segment((4, 4), (6, 0))));
+        assert!(s.intersects(&segment((2, 2), (8, 8))));
+        // on the same line but apart, parallel, and crossing the line beyond the segment
+        assert!(!s.intersects(&segment((5, 5), (8, 8))));
+        assert!(!s.intersects(&segment((1, 0), (5, 4))));
+        assert!(!s.intersects(&segment((5, 0), (9, -4))));
+        // a single point
+        assert!(s.intersects(&segment((3, 3), (3, 3))));
+    }
+
+    #[test]
+    fn lines_cross_at_exact_points() {
+        let a = Line::new(p(0, 0), p(3, 1));
+        let b = Line::new(p(0, 1), p(1, -1));
+
+        let crossing = a.crossing(&b).unwrap();
+        assert_eq!(crossing.point, Point::new(Rational::new(3, 4), Rational::new(1, 4)));
+        assert_eq!(crossing.t, Rational::new(1, 4));
+        assert_eq!(crossing.u, Rational::new(3, 4));
+        assert_eq!(b.at(crossing.u), crossing.point);
+
+        assert_eq!(a.crossing(&Line::new(p(5, 5), p(-6, -2))), None);
+    }
+
+    #[test]
+    fn area_and_lattice_points_of_a_rectangle() {
+        let rectangle = Polygon::new(vec![p(0, 0), p(4, 0), p(4, 3), p(0, 3)]);
+
+        assert_eq!(rectangle.double_area(), 24);
+        assert_eq!(rectangle.area(), Rational::from(12i64));
+        assert_eq!(rectangle.boundary_points(), 14);
+        assert_eq!(rectangle.interior_points(), 6);
+
+        // the same going the other way, with points in the middle of the edges
+        let reversed = Polygon::new(vec![p(0, 3), p(2, 3), p(4, 3), p(4, 0), p(0, 0)]);
+        assert_eq!(reversed.double_area(), 24);
+        assert_eq!(reversed.interior_points(), 6);
+    }
+
+    #[test]
+    fn rational_polygon_area() {
+        let half = Rational::new(1, 2);
+        let triangle = Polygon::new(vec![Point::new(Rational::ZERO, Rational::ZERO), Point::new(half, Rational::ZERO), Point::new(Rational::ZERO, half)]);
+
+        assert_eq!(triangle.area(), Rational::new(1, 8));
+    }
+
+    #[test]
+    fn locate_points_in_a_concave_polygon() {
+        // an L, with the vertex (2, 2) level with the points looked at
+        let l = Polygon::new(vec![p(0, 0), p(4, 0), p(4, 2), p(2, 2), p(2, 4), p(0, 4)]);
+
+        assert_eq!(l.locate(p(1, 1)), Location::Inside);
+        assert_eq!(l.locate(p(1, 2)), Location::Inside);
+        assert_eq!(l.locate(p(1, 3)), Location::Inside);
+        assert_eq!(l.locate(p(3, 3)), Location::Outside);
+        assert_eq!(l.locate(p(-1, 2)), Location::Outside);
+        assert_eq!(l.locate(p(3, 2)), Location::Boundary);
+        assert_eq!(l.locate(p(0, 0)), Location::Boundary);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in coordinate arithmetic")]
+    fn area_overflow_panics() {
+        let big = i64::MAX / 2;
+        Polygon::new(vec![p(0, 0), p(big, 0), p(big, big), p(0, big)]).double_area();
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in coordinate arithmetic")]
+    fn crossing_overflow_panics() {
+        let big = i128::MAX / 2;
+        let a = Line::new(Point::new(0, 0), Point::new(big, 1));
+        a.crossing(&Line::new(Point::new(1, 0), Point::new(1, big)));
+    }
+}
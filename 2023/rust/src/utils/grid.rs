@@ -0,0 +1,249 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::input::{self, ParseResult};
+use crate::utils::direction::Direction;
+
+const OFFSETS_8: [(i64, i64); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/**
+ * A rectangular grid stored row by row.
+ * Positions are (x, y), i.e. (column, row), with (0, 0) at the top left.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    data: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn from_vec(width: usize, height: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), width * height, "grid data does not match its {}x{} size", width, height);
+
+        Self { width, height, data }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let data = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(&mut f).collect();
+
+        Self { width, height, data }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // a signed position, as long as it is inside the grid
+    pub fn checked_pos(&self, (x, y): (i64, i64)) -> Option<(usize, usize)> {
+        if x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    // the position moved by (dx, dy), as long as it stays inside the grid
+    pub fn offset(&self, (x, y): (usize, usize), (dx, dy): (i64, i64)) -> Option<(usize, usize)> {
+        self.checked_pos((x as i64 + dx, y as i64 + dy))
+    }
+
+    // one step from `pos`, as long as it stays inside the grid
+    pub fn step(&self, pos: (usize, usize), dir: Direction) -> Option<(usize, usize)> {
+        self.offset(pos, dir.offset())
+    }
+
+    // `n` steps from `pos` in a straight line, as long as the last one is inside the grid
+    pub fn steps(&self, pos: (usize, usize), dir: Direction, n: usize) -> Option<(usize, usize)> {
+        let (dx, dy) = dir.offset();
+        self.offset(pos, (dx * n as i64, dy * n as i64))
+    }
+
+    // every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.data.iter())
+    }
+
+    // the up, right, down and left positions that are inside the grid
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL.iter().filter_map(move |d| self.step(pos, *d))
+    }
+
+    // the neighbours4 plus the diagonals
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_8.iter().filter_map(move |d| self.offset(pos, *d))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.data.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.data.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, data: self.data.iter().map(f).collect() }
+    }
+
+    // the first position holding `value`, row by row
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where T: PartialEq {
+        self.iter().find(|(_, v)| *v == value).map(|(pos, _)| pos)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where T: PartialEq {
+        self.iter().filter(move |(_, v)| *v == value).map(|(pos, _)| pos)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    // rows become columns
+    pub fn transposed(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+}
+
+impl Grid<char> {
+    // a grid from the puzzle input, where every character must be `allowed`
+    pub fn parse(input: &str, allowed: impl Fn(char) -> bool) -> ParseResult<Self> {
+        let lines = input::grid_lines(input, allowed)?;
+        let data = lines.iter().flat_map(|text| text.chars()).collect();
+
+        Ok(Self::from_vec(lines[0].len(), lines.len(), data))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the {}x{} grid", x, y, self.width, self.height);
+        &self.data[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the {}x{} grid", x, y, self.width, self.height);
+        &mut self.data[y * self.width + x]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for v in row {
+                write!(f, "{}", v)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 4 columns, 3 rows
+    fn grid() -> Grid<char> {
+        Grid::parse("abcd\nefgh\nijkl\n", |ch| ch.is_ascii_lowercase()).unwrap()
+    }
+
+    fn sorted(positions: impl Iterator<Item = (usize, usize)>) -> Vec<(usize, usize)> {
+        let mut positions: Vec<_> = positions.collect();
+        positions.sort();
+        positions
+    }
+
+    #[test]
+    fn positions_inside_the_grid() {
+        let grid = grid();
+
+        assert_eq!(grid.checked_pos((3, 2)), Some((3, 2)));
+        assert_eq!(grid.checked_pos((4, 2)), None);
+        assert_eq!(grid.checked_pos((3, 3)), None);
+        assert_eq!(grid.checked_pos((-1, 0)), None);
+
+        assert_eq!(grid.offset((1, 1), (2, -1)), Some((3, 0)));
+        assert_eq!(grid.offset((1, 1), (-2, 0)), None);
+        assert_eq!(grid[grid.offset((0, 0), (1, 2)).unwrap()], 'j');
+    }
+
+    #[test]
+    fn steps_in_a_straight_line() {
+        let grid = grid();
+
+        assert_eq!(grid.step((0, 0), Direction::Right), Some((1, 0)));
+        assert_eq!(grid.step((0, 0), Direction::Up), None);
+        assert_eq!(grid.steps((0, 1), Direction::Right, 3), Some((3, 1)));
+        assert_eq!(grid.steps((0, 1), Direction::Right, 4), None);
+        assert_eq!(grid.steps((2, 2), Direction::Up, 2), Some((2, 0)));
+        assert_eq!(grid.steps((2, 2), Direction::Left, 0), Some((2, 2)));
+    }
+
+    #[test]
+    fn neighbours_on_corners_and_edges() {
+        let grid = grid();
+
+        assert_eq!(grid.neighbours4((1, 1)).collect::<Vec<_>>(), vec![(1, 0), (2, 1), (1, 2), (0, 1)]);
+        assert_eq!(sorted(grid.neighbours4((0, 0))), vec![(0, 1), (1, 0)]);
+        assert_eq!(sorted(grid.neighbours4((3, 2))), vec![(2, 2), (3, 1)]);
+        assert_eq!(sorted(grid.neighbours4((2, 0))), vec![(1, 0), (2, 1), (3, 0)]);
+
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(sorted(grid.neighbours8((0, 0))), vec![(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(sorted(grid.neighbours8((3, 2))), vec![(2, 1), (2, 2), (3, 1)]);
+        assert_eq!(sorted(grid.neighbours8((0, 1))), vec![(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn transposed_rows_become_columns() {
+        let transposed = grid().transposed();
+
+        assert_eq!((transposed.width(), transposed.height()), (3, 4));
+        assert_eq!(transposed.to_string(), "aei\nbfj\ncgk\ndhl\n");
+        assert_eq!(transposed.transposed(), grid());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(grid.row(1), ['e', 'f', 'g', 'h']);
+        assert_eq!(grid.column(2).collect::<String>(), "cgk");
+        assert_eq!(grid.rows().len(), 3);
+        assert_eq!(grid.find(&'g'), Some((2, 1)));
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_problem() {
+        let error = |input: &str| Grid::parse(input, |ch| ".#".contains(ch)).unwrap_err().to_string();
+
+        assert_eq!(error("..#\n.#\n..#\n"), "2:3: expected 3 columns, found 2");
+        assert_eq!(error("..#\n.#..\n"), "2:4: expected 3 columns, found 4");
+        assert_eq!(error("..#\n.x.\n"), "2:2: unexpected 'x'");
+        assert_eq!(error(""), "1:1: empty grid");
+    }
+
+    #[test]
+    #[should_panic(expected = "(4, 0) is outside the 4x3 grid")]
+    fn indexing_outside_panics() {
+        let _ = grid()[(4, 0)];
+    }
+}
@@ -0,0 +1,341 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/**
+ * The unsigned integers gcd and lcm work on.
+ */
+pub trait Unsigned: Copy + Eq + Ord + Div<Output = Self> + Rem<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Unsigned for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u32, u64, u128, usize);
+
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+// None when the result does not fit in T
+pub fn lcm<T: Unsigned>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+// the lcm of all the numbers (1 when there are none), None when it does not fit in T
+pub fn lcm_all<T: Unsigned>(nums: impl IntoIterator<Item = T>) -> Option<T> {
+    nums.into_iter().try_fold(T::ONE, lcm)
+}
+
+/**
+ * Extended Euclidean algorithm: returns (g, x, y) where g = gcd(a, b) >= 0 and a * x + b * y = g.
+ */
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/**
+ * Generalized Chinese Remainder Theorem: the numbers t with t = offset (mod modulus) for every
+ * (offset, modulus) pair, returned as (offset, modulus) with 0 <= offset < modulus.
+ * The moduli do not need to be coprime and the offsets can be negative or larger than the modulus.
+ * None when a modulus is not positive, the congruences have no common solution, or the combined modulus overflows.
+ */
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    let mut combined = (0, 1);
+
+    for &(offset, modulus) in congruences {
+        if modulus <= 0 {
+            return None;
+        }
+        let (r1, m1) = combined;
+        let r2 = offset.rem_euclid(modulus);
+
+        // r1 + m1 * k = r2 (mod modulus) has a solution only when gcd divides the difference
+        let (g, p, _) = extended_gcd(m1, modulus);
+        let diff = r2 - r1;
+        if diff % g != 0 {
+            return None;
+        }
+
+        let m = (m1 / g).checked_mul(modulus)?;
+        // k = diff / g * p (mod modulus / g), reduced first to keep the product small
+        let step = modulus / g;
+        let k = ((diff / g) % step).checked_mul(p % step)?.rem_euclid(step);
+        let r = m1.checked_mul(k)?.checked_add(r1)?.rem_euclid(m);
+
+        combined = (r, m);
+    }
+
+    Some(combined)
+}
+
+/**
+ * An exact fraction of i128s, always reduced with a positive denominator, so equal values are equal structs.
+ * The arithmetic panics on overflow, in release builds too, as a wrapped value would be silently wrong.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+
+    pub fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "a rational can't have a zero denominator");
+        let g = gcd(num.unsigned_abs(), den.unsigned_abs()) as i128 * den.signum();
+
+        Self { num: num / g, den: den / g }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.num
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.den
+    }
+
+    // None when it is not a whole number
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.den == 1).then_some(self.num)
+    }
+
+    pub fn floor(&self) -> i128 {
+        self.num.div_euclid(self.den)
+    }
+}
+
+// The result of a checked i128 operation, panicking on overflow like the rational arithmetic
+pub fn checked(value: Option<i128>) -> i128 {
+    value.expect("overflow in rational arithmetic")
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Self { num: n as i128, den: 1 }
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(n: i128) -> Self {
+        Self { num: n, den: 1 }
+    }
+}
+
+// the common factors are divided out before multiplying, to keep away from overflow
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let g = gcd(self.den as u128, other.den as u128) as i128;
+
+        let num = checked(self.num.checked_mul(other.den / g)).checked_add(checked(other.num.checked_mul(self.den / g)));
+
+        Self::new(checked(num), checked((self.den / g).checked_mul(other.den)))
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let g1 = gcd(self.num.unsigned_abs(), other.den as u128) as i128;
+        let g2 = gcd(other.num.unsigned_abs(), self.den as u128) as i128;
+
+        Self::new(checked((self.num / g1).checked_mul(other.num / g2)), checked((self.den / g2).checked_mul(other.den / g1)))
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        assert!(other.num != 0, "division of a rational by zero");
+
+        self * Self::new(other.den, other.num)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { num: checked(self.num.checked_neg()), den: self.den }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// the denominators are positive, so cross multiplying keeps the order
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        checked(self.num.checked_mul(other.den)).cmp(&checked(other.num.checked_mul(self.den)))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(gcd(7u64, 0), 7);
+        assert_eq!(lcm(4u64, 6), Some(12));
+        assert_eq!(lcm(0u64, 6), Some(0));
+        assert_eq!(lcm_all([2u64, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all(Vec::<u64>::new()), Some(1));
+    }
+
+    #[test]
+    fn lcm_overflow_is_none() {
+        assert_eq!(lcm(u32::MAX, u32::MAX - 1), None);
+        assert_eq!(lcm_all([1u64 << 40, 3u64.pow(20), 5]), None);
+        // a common factor keeps it in range
+        assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+    }
+
+    #[test]
+    fn extended_gcd_satisfies_the_identity() {
+        for (a, b) in [(240, 46), (46, 240), (-240, 46), (240, -46), (17, 5), (0, 9), (9, 0), (1 << 100, 3 << 90)] {
+            let (g, x, y) = extended_gcd(a, b);
+
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i128, "gcd of {} and {}", a, b);
+            assert_eq!(a * x + b * y, g, "identity for {} and {}", a, b);
+        }
+    }
+
+    #[test]
+    fn crt_of_coprime_moduli() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // negative and larger than the modulus offsets
+        assert_eq!(crt(&[(-1, 3), (8, 5)]), Some((8, 15)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn crt_of_moduli_with_common_factors() {
+        // t = 2 (mod 6) and t = 8 (mod 10) agree mod their gcd 2
+        assert_eq!(crt(&[(2, 6), (8, 10)]), Some((8, 30)));
+        assert_eq!(crt(&[(3, 4), (7, 8), (15, 16)]), Some((15, 16)));
+        // but t = 1 (mod 6) is odd and t = 4 (mod 10) even
+        assert_eq!(crt(&[(1, 6), (4, 10)]), None);
+        assert_eq!(crt(&[(3, 4), (5, 8)]), None);
+    }
+
+    #[test]
+    fn crt_of_non_positive_moduli_is_none() {
+        assert_eq!(crt(&[(2, 3), (1, 0)]), None);
+        assert_eq!(crt(&[(1, -5)]), None);
+    }
+
+    #[test]
+    fn crt_overflow_is_none() {
+        assert_eq!(crt(&[(1, i128::MAX), (2, i128::MAX - 1)]), None);
+    }
+
+    #[test]
+    fn rationals_are_kept_reduced() {
+        assert_eq!(Rational::new(6, -4), Rational::new(-3, 2));
+        assert_eq!(Rational::new(0, -5), Rational::ZERO);
+        assert_eq!(Rational::new(-3, 2).to_string(), "-3/2");
+        assert_eq!(Rational::new(-3, 2).floor(), -2);
+        assert_eq!(Rational::new(8, 4).to_integer(), Some(2));
+    }
+
+    #[test]
+    fn rational_arithmetic_is_exact() {
+        let third = Rational::new(1, 3);
+        let sixth = Rational::new(1, 6);
+
+        assert_eq!(third + sixth, Rational::new(1, 2));
+        assert_eq!(third - sixth, sixth);
+        assert_eq!(third * sixth, Rational::new(1, 18));
+        assert_eq!(third / sixth, Rational::from(2i64));
+        assert!(sixth < third && -third < -sixth);
+
+        // 0.1 + 0.2 == 0.3, unlike in f64
+        assert_eq!(Rational::new(1, 10) + Rational::new(2, 10), Rational::new(3, 10));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in rational arithmetic")]
+    fn rational_sum_overflow_panics() {
+        let _ = Rational::from(i128::MAX) + Rational::new(1, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in rational arithmetic")]
+    fn rational_product_overflow_panics() {
+        let _ = Rational::new(i128::MAX, 3) * Rational::new(5, 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in rational arithmetic")]
+    fn rational_comparison_overflow_panics() {
+        let _ = Rational::new(i128::MAX, 2) < Rational::new(i128::MAX - 2, 3);
+    }
+}
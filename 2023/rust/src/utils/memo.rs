@@ -0,0 +1,133 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/**
+ * A cache of the results of a function keyed on its arguments, any hashable value such as a tuple of indices.
+ * It is a plain value passed down the recursion rather than a wrapper around the function, so recursive functions
+ * can look themselves up: `if let Some(v) = memo.get(&key) { return v; }` and `memo.insert(key, result)` at the end.
+ * With a bound, the oldest results are dropped to make room and will be computed again when needed.
+ */
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    // in insertion order, only kept when there is a bound
+    order: VecDeque<K>,
+    bound: Option<usize>,
+    stats: Stats
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self { values: HashMap::new(), order: VecDeque::new(), bound: None, stats: Stats::default() }
+    }
+
+    // keeps at most `bound` results
+    pub fn bounded(bound: usize) -> Self {
+        assert!(bound > 0, "a memo must hold at least one result");
+
+        Self { bound: Some(bound), ..Self::new() }
+    }
+
+    // the result stored for `key`, counted as a hit or a miss
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.values.get(key).cloned();
+        match value {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1
+        }
+
+        value
+    }
+
+    // stores the result and returns it, so it can end the function being memoized
+    pub fn insert(&mut self, key: K, value: V) -> V {
+        if let Some(bound) = self.bound {
+            if !self.values.contains_key(&key) {
+                if self.values.len() == bound {
+                    let oldest = self.order.pop_front().unwrap();
+                    self.values.remove(&oldest);
+                    self.stats.evictions += 1;
+                }
+                self.order.push_back(key.clone());
+            }
+        }
+
+        self.values.insert(key, value.clone());
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        if let Some(value) = memo.get(&n) {
+            return value;
+        }
+
+        let value = fibonacci(n - 1, memo) + fibonacci(n - 2, memo);
+        memo.insert(n, value)
+    }
+
+    #[test]
+    fn each_value_is_computed_once() {
+        let mut memo = Memo::new();
+
+        assert_eq!(fibonacci(90, &mut memo), 2880067194370816120);
+        assert_eq!(memo.len(), 89);
+        // from fib(4) up, fib(n - 2) is found as fib(n - 1) has just computed it
+        assert_eq!(memo.stats(), Stats { hits: 87, misses: 89, evictions: 0 });
+    }
+
+    #[test]
+    fn bounded_memo_drops_the_oldest_results() {
+        let mut memo = Memo::bounded(2);
+        memo.insert((0, 0), 'a');
+        memo.insert((0, 1), 'b');
+        memo.insert((0, 1), 'c');
+        memo.insert((1, 0), 'd');
+
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.get(&(0, 0)), None);
+        assert_eq!(memo.get(&(0, 1)), Some('c'));
+        assert_eq!(memo.get(&(1, 0)), Some('d'));
+        assert_eq!(memo.stats(), Stats { hits: 2, misses: 1, evictions: 1 });
+    }
+
+    #[test]
+    fn bounded_memo_still_gives_the_right_answer() {
+        let mut memo = Memo::bounded(3);
+
+        assert_eq!(fibonacci(50, &mut memo), 12586269025);
+        assert!(memo.len() <= 3);
+    }
+}
@@ -0,0 +1,11 @@
+pub mod compressed_grid;
+pub mod cycle;
+pub mod direction;
+pub mod geometry;
+pub mod grid;
+pub mod math;
+pub mod memo;
+pub mod range_set;
+pub mod rng;
+pub mod search;
+pub mod tiled_grid;
@@ -0,0 +1,173 @@
+use std::ops::{Add, Range, Sub};
+
+/**
+ * A set of values stored as sorted, disjoint and non-touching half-open ranges.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    pub fn from_range(range: Range<T>) -> Self {
+        Self::from_ranges(vec![range])
+    }
+
+    // any ranges, they can overlap or be empty
+    pub fn from_ranges(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|r| r.start < r.end);
+        ranges.sort_by_key(|r| r.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for r in ranges {
+            match merged.last_mut() {
+                Some(last) if r.start <= last.end => last.end = last.end.max(r.end),
+                _ => merged.push(r)
+            }
+        }
+
+        Self { ranges: merged }
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    // how many values are in the set
+    pub fn count(&self) -> u64
+    where T: Sub<Output = T> + Into<u64> {
+        self.ranges.iter().map(|r| (r.end - r.start).into()).sum()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            // drop whichever range ends first, the other one can still overlap the next
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    // the values of this set that are not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+
+        for r in &self.ranges {
+            let mut start = r.start;
+            for o in other.ranges.iter().filter(|o| o.start < r.end && o.end > r.start) {
+                if o.start > start {
+                    ranges.push(start..o.start);
+                }
+                start = o.end;
+            }
+            if start < r.end {
+                ranges.push(start..r.end);
+            }
+        }
+
+        Self { ranges }
+    }
+
+    // (the values below `at`, the values from `at` on)
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = vec![];
+        let mut above = vec![];
+
+        for r in &self.ranges {
+            if r.end <= at {
+                below.push(r.clone());
+            } else if r.start >= at {
+                above.push(r.clone());
+            } else {
+                below.push(r.start..at);
+                above.push(at..r.end);
+            }
+        }
+
+        (Self { ranges: below }, Self { ranges: above })
+    }
+
+    /**
+     * Moves the values inside each `(source, destination)` piece so that `source.start` ends up at `destination`,
+     * keeping the values no piece covers where they are. The pieces should not overlap.
+     */
+    pub fn map_pieces(&self, pieces: impl IntoIterator<Item = (Range<T>, T)>) -> Self
+    where T: Add<Output = T> + Sub<Output = T> {
+        let mut unmapped = self.clone();
+        let mut mapped = vec![];
+
+        for (source, destination) in pieces {
+            let source = Self::from_range(source);
+            for r in unmapped.intersection(&source).ranges {
+                let start = r.start - source.ranges[0].start + destination;
+                mapped.push(start..start + (r.end - r.start));
+            }
+            unmapped = unmapped.difference(&source);
+        }
+
+        Self::from_ranges(mapped).union(&unmapped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<u32>]) -> RangeSet<u32> {
+        RangeSet::from_ranges(ranges.to_vec())
+    }
+
+    #[test]
+    fn merges_overlapping_and_touching_ranges() {
+        assert_eq!(set(&[5..8, 1..3, 3..4, 7..10, 12..12]).ranges, vec![1..4, 5..10]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[1..10, 20..30]);
+        let b = RangeSet::from_range(5..25);
+
+        assert_eq!(a.union(&b), RangeSet::from_range(1..30));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25]));
+        assert_eq!(a.difference(&b), set(&[1..5, 25..30]));
+        assert_eq!(b.difference(&a), RangeSet::from_range(10..20));
+        assert_eq!(a.count(), 19);
+    }
+
+    #[test]
+    fn split_at_inside_and_between_ranges() {
+        let a = set(&[1..10, 20..30]);
+
+        assert_eq!(a.split_at(5), (RangeSet::from_range(1..5), set(&[5..10, 20..30])));
+        assert_eq!(a.split_at(15), (RangeSet::from_range(1..10), RangeSet::from_range(20..30)));
+        assert_eq!(a.split_at(0), (set(&[]), a.clone()));
+    }
+
+    #[test]
+    fn map_pieces_moves_only_covered_values() {
+        // the day 5 example: seed-to-soil
+        let seeds = set(&[79..93, 55..68]);
+        let soil = seeds.map_pieces([(98..100, 50), (50..98, 52)]);
+
+        assert_eq!(soil, set(&[57..70, 81..95]));
+        assert_eq!(RangeSet::from_range(0..10).map_pieces([(5..8, 100)]), set(&[0..5, 8..10, 100..103]));
+    }
+}
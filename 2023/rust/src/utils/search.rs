@@ -0,0 +1,257 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+use std::ops::Add;
+
+use indexmap::IndexMap;
+use indexmap::map::Entry;
+
+// parent index of the start states
+const NO_PARENT: usize = usize::MAX;
+
+/**
+ * A path found by a search: its total cost and every state from the start to the goal (both included).
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>
+}
+
+// follows the parent indexes back to a start state
+fn reconstruct<S: Clone, V>(visited: &IndexMap<S, V>, parent: impl Fn(&V) -> usize, mut index: usize) -> Vec<S> {
+    let mut states = vec![];
+    while index != NO_PARENT {
+        let (state, value) = visited.get_index(index).unwrap();
+        states.push(state.clone());
+        index = parent(value);
+    }
+    states.reverse();
+
+    states
+}
+
+/**
+ * Breadth first search from `start` to the first state where `is_goal` holds, every step costs 1.
+ * States are expanded in the order they are found, so the path is the first of the shortest ones.
+ */
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>
+{
+    // state -> parent index, in the order they were found (which is also the queue)
+    let mut visited: IndexMap<S, usize> = IndexMap::new();
+    visited.insert(start, NO_PARENT);
+
+    let mut current = 0;
+    while let Some((state, _)) = visited.get_index(current) {
+        if is_goal(state) {
+            let states = reconstruct(&visited, |parent| *parent, current);
+            return Some(Path { cost: states.len() - 1, states });
+        }
+
+        let next_states: Vec<S> = successors(state).into_iter().collect();
+        for next in next_states {
+            visited.entry(next).or_insert(current);
+        }
+
+        current += 1;
+    }
+
+    None
+}
+
+/**
+ * Every state reachable from `start` with its distance, in breadth first order.
+ * With a `max_depth`, states further away than that are not expanded.
+ */
+pub fn reachable<S, I>(start: S, mut successors: impl FnMut(&S) -> I, max_depth: Option<usize>) -> Vec<(S, usize)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>
+{
+    // state -> depth
+    let mut visited: IndexMap<S, usize> = IndexMap::new();
+    visited.insert(start, 0);
+
+    let mut current = 0;
+    while let Some((state, &depth)) = visited.get_index(current) {
+        if max_depth.is_none_or(|max| depth < max) {
+            let next_states: Vec<S> = successors(state).into_iter().collect();
+            for next in next_states {
+                visited.entry(next).or_insert(depth + 1);
+            }
+        }
+
+        current += 1;
+    }
+
+    visited.into_iter().collect()
+}
+
+/**
+ * Cheapest path from any of the `starts` to a state where `is_goal` holds.
+ * `successors` gives the next states along with the cost of moving there.
+ */
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/**
+ * Dijkstra guided by a `heuristic`, which must never overestimate the cost left to a goal.
+ */
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>
+{
+    // state -> (best cost so far, parent index)
+    let mut visited: IndexMap<S, (C, usize)> = IndexMap::new();
+    // (estimated total cost, cost so far, state index), cheapest first
+    let mut open_set = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        let (index, _) = visited.insert_full(start, (C::default(), NO_PARENT));
+        open_set.push(Reverse((estimate, C::default(), index)));
+    }
+
+    while let Some(Reverse((_, cost, index))) = open_set.pop() {
+        let (state, &(best_cost, _)) = visited.get_index(index).unwrap();
+
+        // a cheaper way here was found after this one was queued
+        if cost > best_cost {
+            continue;
+        }
+
+        if is_goal(state) {
+            let states = reconstruct(&visited, |(_, parent)| *parent, index);
+            return Some(Path { cost, states });
+        }
+
+        let next_states: Vec<(S, C)> = successors(state).into_iter().collect();
+        for (next, step_cost) in next_states {
+            let next_cost = cost + step_cost;
+
+            let (next_index, estimate) = match visited.entry(next) {
+                Entry::Vacant(e) => {
+                    let estimate = heuristic(e.key());
+                    let next_index = e.index();
+                    e.insert((next_cost, index));
+                    (next_index, estimate)
+                },
+                Entry::Occupied(mut e) if next_cost < e.get().0 => {
+                    let estimate = heuristic(e.key());
+                    e.insert((next_cost, index));
+                    (e.index(), estimate)
+                },
+                Entry::Occupied(_) => continue
+            };
+
+            open_set.push(Reverse((next_cost + estimate, next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* 0 - 1 - 2 - 3   5 - 6
+        \     /
+         - 4 -              */
+    fn neighbours(n: &u32) -> Vec<u32> {
+        match n {
+            0 => vec![1, 4],
+            1 => vec![0, 2],
+            2 => vec![1, 3, 4],
+            3 => vec![2],
+            4 => vec![0, 2],
+            5 => vec![6],
+            6 => vec![5],
+            _ => vec![]
+        }
+    }
+
+    // the same graph with costs: 0 -> 4 -> 2 is dearer than going through 1
+    fn weighted(n: &u32) -> Vec<(u32, u32)> {
+        neighbours(n).into_iter().map(|m| (m, if *n == 4 || m == 4 { 5 } else { 2 })).collect()
+    }
+
+    #[test]
+    fn bfs_finds_the_first_shortest_path() {
+        let path = bfs(0, neighbours, |n| *n == 3).unwrap();
+        assert_eq!(path, Path { cost: 3, states: vec![0, 1, 2, 3] });
+
+        assert_eq!(bfs(3, neighbours, |n| *n == 3), Some(Path { cost: 0, states: vec![3] }));
+        assert_eq!(bfs(0, neighbours, |n| *n == 6), None);
+    }
+
+    #[test]
+    fn reachable_states_with_their_depth() {
+        let all = reachable(0, neighbours, None);
+        assert_eq!(all, vec![(0, 0), (1, 1), (4, 1), (2, 2), (3, 3)]);
+
+        // states at the max depth are found, but not expanded
+        assert_eq!(reachable(0, neighbours, Some(2)), vec![(0, 0), (1, 1), (4, 1), (2, 2)]);
+        assert_eq!(reachable(0, neighbours, Some(0)), vec![(0, 0)]);
+        assert_eq!(reachable(5, neighbours, None), vec![(5, 0), (6, 1)]);
+    }
+
+    #[test]
+    fn dijkstra_takes_the_cheapest_path() {
+        let path = dijkstra([0], weighted, |n| *n == 3).unwrap();
+        assert_eq!(path, Path { cost: 6, states: vec![0, 1, 2, 3] });
+
+        // 4 is only reached directly, as going round costs more
+        assert_eq!(dijkstra([0], weighted, |n| *n == 4), Some(Path { cost: 5, states: vec![0, 4] }));
+        assert_eq!(dijkstra([0], weighted, |n| *n == 6), None);
+    }
+
+    #[test]
+    fn dijkstra_from_the_cheapest_of_many_starts() {
+        let path = dijkstra([5, 0, 3], weighted, |n| *n == 1).unwrap();
+
+        assert_eq!(path, Path { cost: 2, states: vec![0, 1] });
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra_on_a_grid() {
+        // an open 10x10 grid with a wall along x = 5, but for y = 9
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)].into_iter()
+                .filter(|(x, y)| (0..10).contains(x) && (0..10).contains(y) && (*x != 5 || *y == 9))
+                .map(|p| (p, 1))
+                .collect::<Vec<_>>()
+        };
+        let goal = (9, 0);
+        let manhattan = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+
+        let guided = astar([(0, 0)], successors, manhattan, |p| *p == goal).unwrap();
+        let plain = dijkstra([(0, 0)], successors, |p| *p == goal).unwrap();
+
+        assert_eq!(guided.cost, 27);
+        assert_eq!(guided.cost, plain.cost);
+        assert_eq!(guided.states.len(), 28);
+        assert!(guided.states.windows(2).all(|w| manhattan(&w[0]).abs_diff(manhattan(&w[1])) == 1));
+        assert_eq!((guided.states[0], guided.states[27]), ((0, 0), goal));
+
+        assert_eq!(astar([(0, 0)], successors, manhattan, |p| *p == (10, 0)), None);
+    }
+}
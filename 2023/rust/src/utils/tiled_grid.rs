@@ -0,0 +1,95 @@
+use std::ops::Index;
+
+use crate::utils::direction::Direction;
+use crate::utils::grid::Grid;
+
+/**
+ * A grid repeated forever in every direction, without copying it: any (x, y) is a position, negative ones included,
+ * and is the tile of the grid at (x, y) modulo its size. The grid itself is the copy (0, 0).
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct TiledGrid<'a, T> {
+    grid: &'a Grid<T>
+}
+
+impl<'a, T> TiledGrid<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        assert!(grid.width() > 0 && grid.height() > 0, "an empty grid can't be tiled");
+
+        Self { grid }
+    }
+
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    // the position in the grid that `pos` is a copy of
+    pub fn wrap(&self, (x, y): (i64, i64)) -> (usize, usize) {
+        (x.rem_euclid(self.grid.width() as i64) as usize, y.rem_euclid(self.grid.height() as i64) as usize)
+    }
+
+    // which copy of the grid `pos` is in, (1, 0) being the one right of the grid
+    pub fn copy_of(&self, (x, y): (i64, i64)) -> (i64, i64) {
+        (x.div_euclid(self.grid.width() as i64), y.div_euclid(self.grid.height() as i64))
+    }
+
+    // one step from `pos`, there is always one
+    pub fn step(&self, (x, y): (i64, i64), dir: Direction) -> (i64, i64) {
+        let (dx, dy) = dir.offset();
+        (x + dx, y + dy)
+    }
+
+    // the up, right, down and left positions
+    pub fn neighbours4(&self, pos: (i64, i64)) -> impl Iterator<Item = (i64, i64)> + '_ {
+        Direction::ALL.iter().map(move |d| self.step(pos, *d))
+    }
+}
+
+impl<T> Index<(i64, i64)> for TiledGrid<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: (i64, i64)) -> &T {
+        &self.grid[self.wrap(pos)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("ab\ncd\nef\n", |_| true).unwrap()
+    }
+
+    #[test]
+    fn positions_wrap_around_in_both_directions() {
+        let grid = grid();
+        let tiled = TiledGrid::new(&grid);
+
+        assert_eq!(tiled[(0, 0)], 'a');
+        assert_eq!(tiled[(3, 2)], 'f');
+        assert_eq!(tiled[(-1, -1)], 'f');
+        assert_eq!(tiled[(-4, 7)], 'c');
+        assert_eq!(tiled.wrap((-3, -7)), (1, 2));
+    }
+
+    #[test]
+    fn copies_are_counted_from_the_grid() {
+        let grid = grid();
+        let tiled = TiledGrid::new(&grid);
+
+        assert_eq!(tiled.copy_of((1, 2)), (0, 0));
+        assert_eq!(tiled.copy_of((2, 3)), (1, 1));
+        assert_eq!(tiled.copy_of((-1, -4)), (-1, -2));
+    }
+
+    #[test]
+    fn every_position_has_four_neighbours() {
+        let grid = grid();
+        let tiled = TiledGrid::new(&grid);
+
+        let neighbours: Vec<(i64, i64)> = tiled.neighbours4((0, 0)).collect();
+        assert_eq!(neighbours, vec![(0, -1), (1, 0), (0, 1), (-1, 0)]);
+        assert_eq!(neighbours.iter().map(|p| tiled[*p]).collect::<String>(), "ebcb");
+    }
+}
@@ -0,0 +1,181 @@
+use std::fs;
+use std::panic;
+
+use serde::Deserialize;
+
+use crate::days;
+use crate::generate::Generated;
+use crate::input::{self, InputError};
+use crate::solution::Puzzle;
+
+pub const MANIFEST_FILE: &str = "./answers.toml";
+
+#[derive(Deserialize)]
+struct Manifest {
+    #[serde(rename = "answer")]
+    answers: Vec<Expected>
+}
+
+// the known answers for one input file, relative to ./input
+#[derive(Deserialize)]
+struct Expected {
+    day: u8,
+    input: String,
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>
+}
+
+enum Outcome {
+    Pass,
+    Mismatch(String),
+    Fail(String),
+    // there was no answer to compare with
+    Solved(String)
+}
+
+// Counts the outcomes as they are printed
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    mismatched: usize,
+    failed: usize,
+    solved: usize
+}
+
+impl Tally {
+    fn report(&mut self, name: &str, expected: Option<&str>, outcome: Outcome) {
+        match outcome {
+            Outcome::Pass => {
+                self.passed += 1;
+                println!("PASS     {}", name);
+            },
+            Outcome::Mismatch(actual) => {
+                self.mismatched += 1;
+                println!("MISMATCH {}\n  - {}\n  + {}", name, expected.unwrap_or_default(), actual);
+            },
+            Outcome::Fail(reason) => {
+                self.failed += 1;
+                println!("FAIL     {}: {}", name, reason);
+            },
+            Outcome::Solved(actual) => {
+                self.solved += 1;
+                println!("SOLVED   {}: {}", name, actual);
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.passed + self.mismatched + self.failed + self.solved == 0
+    }
+
+    // Prints the summary and returns whether nothing went wrong
+    fn finish(&self) -> bool {
+        print!("\n{} passed, {} mismatched, {} failed", self.passed, self.mismatched, self.failed);
+        if self.solved > 0 {
+            print!(", {} solved without a known answer", self.solved);
+        }
+        println!();
+
+        self.mismatched == 0 && self.failed == 0
+    }
+}
+
+fn load_manifest(manifest_file: &str) -> Result<Manifest, String> {
+    let text = fs::read_to_string(manifest_file)
+        .map_err(|e| format!("Could not read the answers manifest {}: {}", manifest_file, e))?;
+
+    toml::from_str(&text).map_err(|e| format!("Invalid answers manifest {}: {}", manifest_file, e))
+}
+
+// answers are compared as printed, so both 42 and "42" are accepted in the manifest
+fn answer_text(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        v => v.to_string()
+    }
+}
+
+pub fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "solver panicked".to_string()
+    }
+}
+
+fn check(day: &days::Day, file: &str, text: &str, puzzle: Puzzle, part: u8, expected: Option<&str>) -> Outcome {
+    // failures are reported by the caller, the default hook would also dump them to stderr
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(|| (day.solve)(text, Some(part), puzzle));
+    panic::set_hook(default_hook);
+
+    match result {
+        Ok(Ok(answers)) => match answers.first() {
+            Some(answer) if expected.is_none() => Outcome::Solved(answer.value.clone()),
+            Some(answer) if expected == Some(answer.value.as_str()) => Outcome::Pass,
+            Some(answer) => Outcome::Mismatch(answer.value.clone()),
+            None => Outcome::Fail("no answer".to_string())
+        },
+        Ok(Err(error)) => Outcome::Fail(format!("invalid input {}", InputError::Parse { file: file.to_string(), error })),
+        Err(payload) => Outcome::Fail(panic_message(payload.as_ref()))
+    }
+}
+
+/**
+ * Runs the solvers against every manifest entry matching the day/part/input filters.
+ * Prints a line per answer (with a diff for mismatches) and returns whether all of them passed.
+ */
+pub fn verify(manifest_file: &str, day: Option<u8>, part: Option<u8>, input: Option<&str>) -> Result<bool, String> {
+    let manifest = load_manifest(manifest_file)?;
+
+    let mut tally = Tally::default();
+
+    let selected = manifest.answers.iter()
+        .filter(|e| day.is_none_or(|d| d == e.day))
+        .filter(|e| input.is_none_or(|i| i == e.input));
+
+    for expected in selected {
+        let solver = days::get(expected.day).ok_or(format!("There is no solution for day {}", expected.day))?;
+        let input_file = format!("./input/{}", expected.input);
+        let text = input::read_input(Some(&input_file));
+
+        let parts = [(1, &expected.part1), (2, &expected.part2)];
+        for (n, value) in parts.iter().filter(|(n, _)| part.is_none_or(|p| p == *n)) {
+            let Some(value) = value else { continue };
+            let expected_answer = answer_text(value);
+
+            let outcome = match &text {
+                Ok(text) => check(solver, &input_file, text, solver.puzzle_of(&input_file), *n, Some(&expected_answer)),
+                Err(e) => Outcome::Fail(format!("could not read {}", e))
+            };
+
+            let name = format!("day {:02} part {} ({})", expected.day, n, expected.input);
+            tally.report(&name, Some(&expected_answer), outcome);
+        }
+    }
+
+    if tally.is_empty() {
+        return Err("There are no known answers to verify".to_string());
+    }
+
+    Ok(tally.finish())
+}
+
+/**
+ * Solves a generated input and compares with the answers it was built to have,
+ * the parts without one are only checked to solve. Returns whether all of them did.
+ */
+pub fn check_generated(day: &days::Day, name: &str, part: Option<u8>, generated: &Generated) -> bool {
+    let mut tally = Tally::default();
+
+    for n in [1, 2].into_iter().filter(|n| part.is_none_or(|p| p == *n)) {
+        let expected = generated.answers[n as usize - 1].as_deref();
+        let outcome = check(day, name, &generated.input, Puzzle::Real, n, expected);
+        tally.report(&format!("day {:02} part {} ({})", day.day, n, name), expected, outcome);
+    }
+
+    tally.finish()
+}
@@ -0,0 +1,47 @@
+//! `aoc bench` writes its timings as JSON that a later run can compare against.
+
+use std::env;
+use std::fs;
+use std::process::{Command, Output};
+
+fn bench(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["bench", "13", "--example", "1", "--iterations", "3"])
+        .args(args)
+        .output()
+        .expect("could not run aoc")
+}
+
+#[test]
+fn writes_a_report_it_can_compare_against() {
+    let path = env::temp_dir().join(format!("aoc_bench_{}.json", std::process::id()));
+    let path = path.to_str().unwrap();
+
+    let first = bench(&["--output", path]);
+    assert!(first.status.success(), "{}", String::from_utf8_lossy(&first.stderr));
+    assert!(String::from_utf8_lossy(&first.stdout).contains("13 point_of_incidence"));
+
+    let report: serde_json::Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    let day = &report["days"][0];
+    assert_eq!(day["day"], 13);
+    assert_eq!(day["iterations"], 3);
+    for phase in ["parse", "part1", "part2"] {
+        assert!(day[phase]["median_ns"].as_u64().unwrap() <= day[phase]["p95_ns"].as_u64().unwrap());
+    }
+
+    // the example takes microseconds, below what is ever flagged as a regression
+    let second = bench(&["--baseline", path, "--threshold", "0"]);
+    fs::remove_file(path).unwrap();
+
+    assert!(second.status.success(), "{}", String::from_utf8_lossy(&second.stdout));
+    assert!(String::from_utf8_lossy(&second.stdout).contains("0 regressions above 0% against the baseline"));
+}
+
+#[test]
+fn missing_baseline_is_an_error() {
+    let output = bench(&["--baseline", "./no_such_baseline.json"]);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Could not read the baseline ./no_such_baseline.json"));
+}
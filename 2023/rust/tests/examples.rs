@@ -0,0 +1,81 @@
+//! Runs every day against the bundled example inputs and checks the published
+//! answers recorded in answers.toml (see `aoc verify`).
+
+use std::process::Command;
+
+fn verify(day: u8, input: &str, part: Option<u8>) {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    cmd.current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["verify", &day.to_string(), "--input", input]);
+    if let Some(p) = part {
+        cmd.args(["--part", &p.to_string()]);
+    }
+
+    let output = cmd.output().expect("could not run aoc");
+
+    assert!(output.status.success(), "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr));
+}
+
+macro_rules! examples {
+    ($($(#[$attr:meta])* $name:ident: $day:expr, $input:expr $(, part $part:expr)?;)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                verify($day, $input, None $(.or(Some($part)))?);
+            }
+        )*
+    };
+}
+
+examples! {
+    d05_example: 5, "05_input_test.txt";
+    d06_example: 6, "06_input_test.txt";
+    d07_example: 7, "07_input_test.txt";
+    d08_example: 8, "08_input_test.txt";
+    d08_example2: 8, "08_input_test2.txt";
+    d08_example3: 8, "08_input_test3.txt";
+    d09_example: 9, "09_input_test.txt";
+    d10_example: 10, "10_input_test.txt";
+    d10_example2: 10, "10_input_test2.txt";
+    d10_example3: 10, "10_input_test3.txt";
+    d10_example4: 10, "10_input_test4.txt";
+    d10_example5: 10, "10_input_test5.txt";
+    d11_example: 11, "11_input_test.txt";
+    d12_example: 12, "12_input_test.txt";
+    d13_example: 13, "13_input_test.txt";
+    d14_example: 14, "14_input_test.txt";
+    d15_example: 15, "15_input_test.txt";
+    d16_example: 16, "16_input_test.txt";
+    d17_example: 17, "17_input_test.txt";
+    // 18_input_test2.txt is the drawing of the example lagoon, not a dig plan
+    d18_example: 18, "18_input_test.txt";
+    d19_example: 19, "19_input_test.txt";
+    d20_example: 20, "20_input_test.txt";
+    d20_example2: 20, "20_input_test2.txt";
+    d21_example: 21, "21_input_test.txt";
+    d22_example: 22, "22_input_test.txt";
+    d23_example: 23, "23_input_test.txt";
+    d24_example_part1: 24, "24_input_test.txt", part 1;
+    d24_example_part2: 24, "24_input_test.txt", part 2;
+    d25_example: 25, "25_input_test.txt";
+}
+
+#[test]
+fn d10_render_example() {
+    let dir = format!("{}/render", env!("CARGO_TARGET_TMPDIR"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["run", "10", "--example", "1", "--render", &dir])
+        .output()
+        .expect("could not run aoc");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    // the 5x5 example, 4 pixels per tile
+    let ppm = std::fs::read(format!("{}/10_loop.ppm", dir)).expect("no picture was rendered");
+    assert!(ppm.starts_with(b"P6\n20 20\n255\n"));
+    assert_eq!(ppm.len(), "P6\n20 20\n255\n".len() + 20 * 20 * 3);
+}
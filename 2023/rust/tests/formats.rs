@@ -0,0 +1,60 @@
+//! `aoc run --format json|tsv` prints the same fields for every day, for other programs to read.
+
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .arg("run")
+        .args(args)
+        .output()
+        .expect("could not run aoc")
+}
+
+#[test]
+fn json_lists_every_answer() {
+    let output = run(&["13", "--example", "1", "--format", "json"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let results: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let day = &results[0];
+    assert_eq!(day["day"], 13);
+    assert_eq!(day["input"], "./input/13_input_test.txt");
+    assert_eq!(day["status"], "ok");
+    assert_eq!(day["answers"][0]["part"], 1);
+    assert_eq!(day["answers"][0]["answer"], "405");
+    assert_eq!(day["answers"][1]["answer"], "400");
+    assert!(day["answers"][1]["time_ns"].is_u64());
+}
+
+#[test]
+fn tsv_reports_failures_with_their_status() {
+    let output = run(&["6", "--input", "./answers.toml", "--part", "2", "--format", "tsv"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<Vec<&str>> = stdout.lines().map(|line| line.split('\t').collect()).collect();
+    assert_eq!(lines[0], ["day", "part", "answer", "input", "time_ns", "status", "error"]);
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1][..4], ["6", "", "", "./answers.toml"]);
+    assert_eq!(lines[1][5], "invalid_input");
+    // the diagnostic is still printed for people, on stderr
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid puzzle input ./answers.toml"));
+}
+
+#[test]
+fn tsv_has_a_line_per_part() {
+    let output = run(&["5", "--example", "1", "--format", "tsv"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let answers: Vec<&str> = stdout.lines().skip(1).map(|line| line.split('\t').nth(2).unwrap()).collect();
+    assert_eq!(answers, ["35", "46"]);
+}
+
+#[test]
+fn unknown_format_is_rejected() {
+    let output = run(&["5", "--format", "xml"]);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid format 'xml'"));
+}
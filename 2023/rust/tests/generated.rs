@@ -0,0 +1,59 @@
+//! Solves a random input for every day (see `aoc generate`), and checks the
+//! answers the generators planted in them when they know them.
+
+use std::process::Command;
+
+fn check(day: u8, part: Option<u8>) {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    cmd.args(["generate", &day.to_string(), "--seed", "7", "--size", "30", "--check"]);
+    if let Some(p) = part {
+        cmd.args(["--part", &p.to_string()]);
+    }
+
+    let output = cmd.output().expect("could not run aoc");
+
+    assert!(output.status.success(), "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr));
+}
+
+macro_rules! generated {
+    ($($(#[$attr:meta])* $name:ident: $day:expr $(, part $part:expr)?;)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                check($day, None $(.or(Some($part)))?);
+            }
+        )*
+    };
+}
+
+generated! {
+    d01_generated: 1;
+    d02_generated: 2;
+    d03_generated: 3;
+    d04_generated: 4;
+    d05_generated: 5;
+    d06_generated: 6;
+    d07_generated: 7;
+    d08_generated: 8;
+    d09_generated: 9;
+    d10_generated: 10;
+    d11_generated: 11;
+    d12_generated: 12;
+    d13_generated: 13;
+    d14_generated: 14;
+    d15_generated: 15;
+    d16_generated: 16;
+    d17_generated: 17;
+    d18_generated: 18;
+    d19_generated: 19;
+    d20_generated: 20;
+    d21_generated: 21;
+    d22_generated: 22;
+    d23_generated: 23;
+    d24_generated_part1: 24, part 1;
+    d24_generated_part2: 24, part 2;
+    d25_generated: 25;
+}
@@ -0,0 +1,92 @@
+//! Inputs that look right but can't be solved are parse errors pointing at the problem, or answers saying
+//! why there is none, not panics.
+
+use advent_of_code_2023::days;
+use advent_of_code_2023::solution::Puzzle;
+
+fn error(day: u8, text: &str) -> String {
+    match (days::get(day).unwrap().solve)(text, None, Puzzle::Real) {
+        Ok(_) => panic!("day {} solved an invalid input", day),
+        Err(e) => e.to_string()
+    }
+}
+
+fn answers(day: u8, text: &str) -> Vec<String> {
+    (days::get(day).unwrap().solve)(text, None, Puzzle::Real).unwrap().into_iter().map(|a| a.value).collect()
+}
+
+#[test]
+fn workflows_send_parts_to_known_workflows() {
+    assert_eq!(error(19, "in{a<5:zz,R}\n\n{x=1,m=2,a=3,s=4}\n"), "1:8: no workflow named 'zz'");
+    assert_eq!(error(19, "in{a<5:R,qq}\nqq{s>1:A}\n\n{x=1,m=2,a=3,s=4}\n"),
+        "2:4: the last rule of a workflow must have no condition");
+}
+
+#[test]
+fn trail_map_has_an_entrance_and_an_exit() {
+    assert_eq!(error(23, "###\n###\n###\n"), "1:1: expected a single '.' as the entrance, found 0 open tiles");
+    assert_eq!(error(23, "#.#\n#.#\n...\n"), "3:1: expected a single '.' as the exit, found 3 open tiles");
+    assert_eq!(error(23, "#.#\n###\n#.#\n"), "3:2: the exit can't be reached from the entrance");
+}
+
+#[test]
+fn empty_inputs_are_rejected() {
+    assert_eq!(error(18, ""), "1:1: empty dig plan");
+    assert_eq!(error(20, ""), "1:1: missing the 'broadcaster' module");
+    assert_eq!(error(24, ""), "1:1: expected at least 3 hailstones, found 0");
+}
+
+#[test]
+fn only_a_conjunction_sends_pulses_to_rx() {
+    assert_eq!(error(20, "broadcaster -> a\n%a -> rx\n"), "2:1: the module sending pulses to rx must be a conjunction");
+    assert_eq!(error(20, "broadcaster -> a, b\n&a -> rx\n&b -> rx\n"), "3:1: a single module can send pulses to rx");
+}
+
+#[test]
+fn dig_plans_come_back_to_their_start() {
+    let plan = ["R 2 (#000020)", "D 2 (#000021)", "L 2 (#000022)", "U 2 (#000023)"];
+    assert!((days::get(18).unwrap().solve)(&plan.join("\n"), None, Puzzle::Real).is_ok());
+
+    assert_eq!(error(18, &plan[..3].join("\n")), "3:1: the dig plan ends at (0, 2), not back at its start");
+    assert_eq!(error(18, &[plan[0], plan[1], plan[2], "U 2 (#000020)"].join("\n")),
+        "4:1: the dig plan in the colors ends at (2, 2), not back at its start");
+    assert_eq!(error(18, "R 2 (#000024)"), "1:12: invalid direction '4' in color, expected 0 to 3");
+}
+
+#[test]
+fn seeds_come_in_ranges() {
+    assert_eq!(error(5, "seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n"), "1:16: missing the length of the last seed range");
+}
+
+#[test]
+fn pipes_make_a_loop_through_the_start() {
+    assert_eq!(answers(10, ".....\n.S-7.\n.|.|.\n.L-J.\n....."), ["4", "1"]);
+
+    assert_eq!(error(10, "S.\n..\n"), "1:1: the starting point is not connected to any pipe");
+    assert_eq!(error(10, "S-.\n"), "1:3: the loop is broken, '.' does not connect back");
+    assert_eq!(error(10, "S--\n"), "1:3: the loop runs off the maze");
+    assert_eq!(error(10, "S-S\n"), "1:1: the maze must have exactly one starting point 'S'");
+}
+
+#[test]
+fn bricks_can_be_given_upside_down() {
+    let bricks = "1,0,1~1,2,1\n0,0,2~2,0,2\n0,2,3~2,2,3\n0,0,4~0,2,4\n1,1,5~1,1,6\n";
+    let flipped = "1,2,1~1,0,1\n0,0,2~2,0,2\n0,2,3~2,2,3\n0,0,4~0,2,4\n1,1,6~1,1,5\n";
+
+    assert_eq!(answers(22, flipped), answers(22, bricks));
+}
+
+#[test]
+fn crucibles_may_not_reach_the_factory() {
+    assert_eq!(answers(17, "12345\n"), ["the crucible can't reach the factory", "14"]);
+}
+
+#[test]
+fn ghosts_may_never_meet_on_z_nodes() {
+    let network = "L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)\n";
+    assert_eq!(answers(8, network), ["0", "the ghosts are never all on **Z nodes at the same time"]);
+
+    // and AAA may never get to ZZZ
+    assert_eq!(answers(8, "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n")[0], "0");
+    assert_eq!(error(8, "\n\nAAA = (AAA, AAA)\n"), "1:1: missing input path");
+}
@@ -0,0 +1,38 @@
+//! Uses the crate as a library, the way another crate depending on
+//! advent_of_code_2023 would.
+
+use advent_of_code_2023::days::{self, d17::ClumsyCrucible};
+use advent_of_code_2023::solution::{self, Puzzle, Solution};
+use advent_of_code_2023::utils::grid::Grid;
+use advent_of_code_2023::utils::search;
+
+const EXAMPLE_17: &str = include_str!("../input/17_input_test.txt");
+
+#[test]
+fn solves_a_day_through_its_solution() {
+    let map = ClumsyCrucible::parse(EXAMPLE_17).unwrap();
+
+    assert_eq!(ClumsyCrucible::part1(&map), "102");
+    assert_eq!(ClumsyCrucible::part2(&map), "94");
+}
+
+#[test]
+fn solves_a_day_from_the_registry() {
+    let answers = (days::get(17).unwrap().solve)(EXAMPLE_17, Some(1), Puzzle::Example).unwrap();
+    assert_eq!(answers[0].value, "102");
+
+    let answers = solution::solve::<ClumsyCrucible>(EXAMPLE_17, None, Puzzle::Example).unwrap();
+    assert_eq!(answers.iter().map(|a| a.value.as_str()).collect::<Vec<_>>(), ["102", "94"]);
+}
+
+#[test]
+fn reuses_the_grid_and_search_utilities() {
+    let maze = Grid::parse("S.#\n..#\n#..\n", |ch| ".#S".contains(ch)).unwrap();
+    let start = maze.find(&'S').unwrap();
+
+    let path = search::bfs(start,
+        |&pos| maze.neighbours4(pos).filter(|p| maze[*p] != '#').collect::<Vec<_>>(),
+        |&pos| pos == (2, 2));
+
+    assert_eq!(path.map(|p| p.cost), Some(4));
+}
@@ -0,0 +1,32 @@
+//! Running the days on several threads must print exactly what a single
+//! thread does, in the same order.
+
+use std::fs;
+use std::process::{Command, Output};
+
+fn run_all(threads: usize) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["run", "all", "--example", "1", "--part", "1", "--threads", &threads.to_string()])
+        .output()
+        .expect("could not run aoc")
+}
+
+#[test]
+fn threads_do_not_change_the_output() {
+    let single = run_all(1);
+    let parallel = run_all(4);
+
+    assert!(single.status.success(), "{}", String::from_utf8_lossy(&single.stderr));
+    assert_eq!(String::from_utf8_lossy(&parallel.stdout), String::from_utf8_lossy(&single.stdout));
+    assert_eq!(String::from_utf8_lossy(&parallel.stderr), String::from_utf8_lossy(&single.stderr));
+    assert_eq!(parallel.status.code(), single.status.code());
+
+    // only the days with a first example run, each with its part 1 answer
+    let examples = fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/input")).unwrap()
+        .filter(|entry| entry.as_ref().unwrap().file_name().to_string_lossy().ends_with("_input_test.txt"))
+        .count();
+    let stdout = String::from_utf8_lossy(&single.stdout);
+    assert_eq!(stdout.lines().filter(|line| line.starts_with("== Day ")).count(), examples);
+    assert_eq!(stdout.lines().filter(|line| line.starts_with("[Part 1] ")).count(), examples);
+}